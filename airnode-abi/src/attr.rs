@@ -0,0 +1,80 @@
+//! Helpers for `#[serde(with = "...")]` attributes, that force the type of the parameter
+//! in `to_abi`. Other serializers (like JSON) see the value as it is.
+//!
+//! `U256` and `H160` are serialized as hex strings, so their fields should be marked
+//! with `attr::uint256`, `attr::bytes32` or `attr::address`. `to_abi` fails on the
+//! unmarked `0x`-prefixed strings, `attr::string` keeps them as `String`.
+//!
+//! ```
+//! use airnode_abi::{attr, to_abi};
+//! use ethereum_types::{H160, U256};
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! struct Request {
+//!     #[serde(with = "attr::uint256")]
+//!     amount: U256,
+//!     #[serde(with = "attr::address")]
+//!     wallet: H160,
+//!     #[serde(with = "attr::string32")]
+//!     from: String,
+//!     #[serde(with = "attr::date")]
+//!     date: (i32, u32, u32),
+//! }
+//!
+//! let abi = to_abi(&Request {
+//!     amount: U256::from(1000),
+//!     wallet: H160::zero(),
+//!     from: "ETH".to_owned(),
+//!     date: (2021, 7, 1),
+//! })
+//! .unwrap();
+//! assert_eq!(abi.schema, "1uass");
+//! ```
+
+pub(crate) const BYTES32_TOKEN: &str = "$airnode_abi::Bytes32";
+pub(crate) const STRING32_TOKEN: &str = "$airnode_abi::String32";
+pub(crate) const DATE_TOKEN: &str = "$airnode_abi::Date";
+pub(crate) const UINT256_TOKEN: &str = "$airnode_abi::Uint256";
+pub(crate) const INT256_TOKEN: &str = "$airnode_abi::Int256";
+pub(crate) const ADDRESS_TOKEN: &str = "$airnode_abi::Address";
+pub(crate) const STRING_TOKEN: &str = "$airnode_abi::String";
+
+macro_rules! forced_type {
+    ($name:ident, $token:ident) => {
+        pub mod $name {
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: Serialize + ?Sized,
+                S: Serializer,
+            {
+                serializer.serialize_newtype_struct(super::$token, value)
+            }
+
+            pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: Deserialize<'de>,
+                D: Deserializer<'de>,
+            {
+                T::deserialize(deserializer)
+            }
+        }
+    };
+}
+
+// `U256`, integer or 0x-prefixed hex string, stored as `Uint256`
+forced_type!(uint256, UINT256_TOKEN);
+// `I256` or integer, stored as `Int256`. `I256` is marked by itself and needs no attribute
+forced_type!(int256, INT256_TOKEN);
+// `H160` or 0x-prefixed hex string of 20 bytes, stored as `Address`
+forced_type!(address, ADDRESS_TOKEN);
+// any string, including the 0x-prefixed ones, stored as `String`
+forced_type!(string, STRING_TOKEN);
+// `U256` or 0x-prefixed hex string of 32 bytes, stored as `Bytes32`
+forced_type!(bytes32, BYTES32_TOKEN);
+// string of up to 32 bytes, stored as `String32`
+forced_type!(string32, STRING32_TOKEN);
+// tuple of year, month and day, stored as `Date`
+forced_type!(date, DATE_TOKEN);
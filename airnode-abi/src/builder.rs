@@ -0,0 +1,295 @@
+use crate::decode::str_to_datetime;
+use crate::encode::validate_date;
+use crate::prelude::*;
+use crate::{Param, ABI, I256};
+use core::fmt;
+use ethereum_types::{H160, U256};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Violation of the ABI encoding rules, found before encoding
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum BuildError {
+    #[error("too many parameters {0}, max is 31")]
+    TooManyParams(usize),
+    #[error("parameter {param}: name {name} exceeds 32 bytes")]
+    NameTooLong { param: usize, name: String },
+    #[error("parameter {param}: duplicate name {name}")]
+    DuplicateName { param: usize, name: String },
+    #[error("parameter {param} ({name}): value exceeds 32 bytes")]
+    ValueTooLong { param: usize, name: String },
+    #[error("parameter {param} ({name}): {reason}")]
+    InvalidDate {
+        param: usize,
+        name: String,
+        reason: String,
+    },
+}
+
+/// All violations that were found in the parameters
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub struct BuildErrors(pub Vec<BuildError>);
+
+impl fmt::Display for BuildErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list: Vec<String> = self.0.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}", list.join("; "))
+    }
+}
+
+impl ABI {
+    /// checks the parameters against the rules of encoding:
+    /// number of parameters, length of names and 32 bytes values, uniqueness of names and dates.
+    /// Returns every violation that was found
+    pub fn validate(&self) -> Result<(), BuildErrors> {
+        let mut errors = vec![];
+        if self.params.len() > 31 {
+            errors.push(BuildError::TooManyParams(self.params.len()));
+        }
+        let mut names: Set<&str> = Set::new();
+        for (param, p) in self.params.iter().enumerate() {
+            let name = p.get_name();
+            if name.len() > 32 {
+                errors.push(BuildError::NameTooLong {
+                    param,
+                    name: name.to_owned(),
+                });
+            }
+            if !names.insert(name) {
+                errors.push(BuildError::DuplicateName {
+                    param,
+                    name: name.to_owned(),
+                });
+            }
+            let invalid_date = |reason: String| BuildError::InvalidDate {
+                param,
+                name: name.to_owned(),
+                reason,
+            };
+            match p {
+                Param::String32 { value, .. } | Param::DateTime { value, .. }
+                    if value.len() > 32 =>
+                {
+                    errors.push(BuildError::ValueTooLong {
+                        param,
+                        name: name.to_owned(),
+                    });
+                }
+                Param::DateTime { value, .. } if !str_to_datetime(value) => {
+                    errors.push(invalid_date(format!("invalid RFC3339 date {}", value)));
+                }
+                Param::Date {
+                    year, month, day, ..
+                } => {
+                    if let Err(e) = validate_date(*year, *month, *day) {
+                        errors.push(invalid_date(e.to_string()));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(BuildErrors(errors))
+        }
+    }
+}
+
+/// Builder of the ABI with typed parameters.
+/// Parameters are validated in `build()`, that reports all violations at once.
+///
+/// ```
+/// use airnode_abi::AbiBuilder;
+///
+/// let abi = AbiBuilder::new()
+///     .string32("from", "ETH")
+///     .string32("to", "USD")
+///     .uint("_times", 1_000_000u64)
+///     .build()
+///     .unwrap();
+/// assert_eq!(abi.schema, "1ssu");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AbiBuilder {
+    params: Vec<Param>,
+}
+
+impl AbiBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// adds parameter of any type
+    pub fn param(mut self, param: Param) -> Self {
+        self.params.push(param);
+        self
+    }
+
+    /// adds `Address` parameter
+    pub fn address(self, name: &str, value: H160) -> Self {
+        self.param(Param::Address {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// adds `Bool` parameter, that is encoded as `String32`
+    pub fn bool(self, name: &str, value: bool) -> Self {
+        self.param(Param::Bool {
+            name: name.to_owned(),
+            value,
+        })
+    }
+
+    /// adds `Bytes` parameter
+    pub fn bytes<T: Into<Vec<u8>>>(self, name: &str, value: T) -> Self {
+        self.param(Param::Bytes {
+            name: name.to_owned(),
+            value: value.into(),
+        })
+    }
+
+    /// adds `Bytes32` parameter
+    pub fn bytes32<T: Into<U256>>(self, name: &str, value: T) -> Self {
+        self.param(Param::Bytes32 {
+            name: name.to_owned(),
+            value: value.into(),
+        })
+    }
+
+    /// adds `Date` parameter, that is encoded as `String32`
+    pub fn date(self, name: &str, year: i32, month: u32, day: u32) -> Self {
+        self.param(Param::Date {
+            name: name.to_owned(),
+            year,
+            month,
+            day,
+        })
+    }
+
+    /// adds `DateTime` parameter, that is encoded as `String32`
+    pub fn datetime(self, name: &str, value: &str) -> Self {
+        self.param(Param::DateTime {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        })
+    }
+
+    /// adds `Int256` parameter
+    pub fn int<T: Into<I256>>(self, name: &str, value: T) -> Self {
+        self.param(Param::Int256 {
+            name: name.to_owned(),
+            value: value.into(),
+        })
+    }
+
+    /// adds `String` parameter
+    pub fn string(self, name: &str, value: &str) -> Self {
+        self.param(Param::String {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        })
+    }
+
+    /// adds `String32` parameter
+    pub fn string32(self, name: &str, value: &str) -> Self {
+        self.param(Param::String32 {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        })
+    }
+
+    /// adds `Uint256` parameter
+    pub fn uint<T: Into<U256>>(self, name: &str, value: T) -> Self {
+        self.param(Param::Uint256 {
+            name: name.to_owned(),
+            value: value.into(),
+        })
+    }
+
+    /// returns ABI, if all parameters are valid, or the list of all violations
+    pub fn build(self) -> Result<ABI, BuildErrors> {
+        let abi = ABI::new(self.params);
+        abi.validate()?;
+        Ok(abi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn it_builds_typed_params() {
+        let wallet: H160 = hex!("4128922394C63A204Dd98ea6fbd887780b78bb7d").into();
+        let abi = AbiBuilder::new()
+            .address("wallet", wallet)
+            .bool("flag", true)
+            .bytes("data", vec![1u8, 2, 3])
+            .bytes32("key", 1u64)
+            .date("start", 2021, 2, 28)
+            .int("delta", -5i64)
+            .string("_path", "data.price")
+            .string32("from", "ETH")
+            .uint("_times", 100u64)
+            .build()
+            .unwrap();
+        assert_eq!(abi.schema, "1asBbsiSsu");
+        assert_eq!(abi.get_address("wallet").unwrap(), wallet);
+        assert_eq!(abi.get_i256("delta").unwrap(), I256::from(-5));
+        assert!(abi.encode().is_ok());
+    }
+
+    #[test]
+    fn it_reports_every_violation() {
+        let long = "a".repeat(33);
+        let mut builder = AbiBuilder::new()
+            .uint(&long, 1u64)
+            .string32("from", &long)
+            .date("start", 2021, 2, 29)
+            .date("end", 2021, 0, 1)
+            .datetime("at", "2021-07-01")
+            .string("from", "ETH");
+        for i in 0..26 {
+            builder = builder.uint(&format!("p{}", i), i as u64);
+        }
+        let errors = builder.build().unwrap_err().0;
+        assert_eq!(errors.len(), 7);
+        assert_eq!(errors[0], BuildError::TooManyParams(32));
+        assert_eq!(
+            errors[1],
+            BuildError::NameTooLong {
+                param: 0,
+                name: long.clone()
+            }
+        );
+        assert_eq!(
+            errors[2],
+            BuildError::ValueTooLong {
+                param: 1,
+                name: "from".to_owned()
+            }
+        );
+        assert!(matches!(
+            errors[3],
+            BuildError::InvalidDate { param: 2, .. }
+        ));
+        assert!(matches!(
+            errors[4],
+            BuildError::InvalidDate { param: 3, .. }
+        ));
+        assert!(matches!(
+            errors[5],
+            BuildError::InvalidDate { param: 4, .. }
+        ));
+        assert_eq!(
+            errors[6],
+            BuildError::DuplicateName {
+                param: 5,
+                name: "from".to_owned()
+            }
+        );
+    }
+}
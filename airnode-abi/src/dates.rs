@@ -0,0 +1,202 @@
+//! Conversions of dates and times from/to `chrono` types, that are enabled with `chrono` feature.
+//!
+//! - `Date` parameter is converted from/to `NaiveDate`
+//! - `DateTime` parameter is ISO-8601 (RFC3339) string, encoded as `String32`
+//! - Unix timestamps are `Uint256` parameters with the number of seconds
+//!
+//! ```
+//! use airnode_abi::{Param, ABI};
+//! use chrono::{NaiveDate, TimeZone, Utc};
+//!
+//! let at = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+//! let abi = ABI::new(vec![
+//!     Param::date("date", NaiveDate::from_ymd_opt(2021, 7, 1).unwrap()),
+//!     Param::datetime("at", &at),
+//!     Param::timestamp("_ts", &at).unwrap(),
+//! ]);
+//! assert_eq!(abi.schema, "1ssu");
+//! assert_eq!(abi.get_datetime("at").unwrap(), at);
+//! assert_eq!(abi.get_timestamp("_ts").unwrap(), at);
+//! ```
+use crate::decode::str_to_date;
+use crate::prelude::*;
+use crate::{Param, ParamError, ABI};
+use chrono::{DateTime, FixedOffset, NaiveDate, SecondsFormat, TimeZone, Utc};
+use ethereum_types::U256;
+
+impl Param {
+    /// returns `Date` parameter from the calendar date
+    pub fn date(name: &str, value: NaiveDate) -> Self {
+        use chrono::Datelike;
+        Self::Date {
+            name: name.to_owned(),
+            year: value.year(),
+            month: value.month(),
+            day: value.day(),
+        }
+    }
+
+    /// returns `DateTime` parameter with RFC3339 representation of the time,
+    /// like `2021-07-01T12:00:00Z`. Fractions of the second are kept,
+    /// `ABI::validate` reports values that do not fit into 32 bytes
+    pub fn datetime<Tz: TimeZone>(name: &str, value: &DateTime<Tz>) -> Self
+    where
+        Tz::Offset: core::fmt::Display,
+    {
+        Self::DateTime {
+            name: name.to_owned(),
+            value: value.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        }
+    }
+
+    /// returns `Uint256` parameter with Unix timestamp (in seconds) of the time,
+    /// or `None` for the time before 1970-01-01
+    pub fn timestamp<Tz: TimeZone>(name: &str, value: &DateTime<Tz>) -> Option<Self> {
+        let seconds = value.timestamp();
+        if seconds < 0 {
+            return None;
+        }
+        Some(Self::Uint256 {
+            name: name.to_owned(),
+            value: U256::from(seconds as u64),
+        })
+    }
+
+    /// returns calendar date of `Date` parameter, or `String32` parameter with `YYYY-MM-DD` value.
+    /// `None` if the parameter is of another type or the date does not exist
+    pub fn to_naive_date(&self) -> Option<NaiveDate> {
+        let (year, month, day) = match self {
+            Self::Date {
+                year, month, day, ..
+            } => (*year, *month, *day),
+            Self::String32 { value, .. } => str_to_date(value)?,
+            _ => return None,
+        };
+        NaiveDate::from_ymd_opt(year, month, day)
+    }
+
+    /// returns time of `DateTime` parameter, or `String32` parameter with RFC3339 value
+    pub fn to_datetime(&self) -> Option<DateTime<FixedOffset>> {
+        match self {
+            Self::DateTime { value, .. } | Self::String32 { value, .. } => {
+                DateTime::parse_from_rfc3339(value).ok()
+            }
+            _ => None,
+        }
+    }
+
+    /// returns time of `Uint256` parameter, that is Unix timestamp (in seconds)
+    pub fn to_timestamp(&self) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Uint256 { value, .. } if value.bits() < 64 => {
+                Utc.timestamp_opt(value.low_u64() as i64, 0).single()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ABI {
+    /// returns calendar date of `Date` parameter
+    pub fn get_naive_date(&self, key: &str) -> Result<NaiveDate, ParamError> {
+        self.typed(key, "date", Param::to_naive_date)
+    }
+
+    /// returns time of `DateTime` parameter
+    pub fn get_datetime(&self, key: &str) -> Result<DateTime<FixedOffset>, ParamError> {
+        self.typed(key, "datetime", Param::to_datetime)
+    }
+
+    /// returns time of `Uint256` parameter, that is Unix timestamp (in seconds)
+    pub fn get_timestamp(&self, key: &str) -> Result<DateTime<Utc>, ParamError> {
+        self.typed(key, "timestamp", Param::to_timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AbiBuilder, BuildError, DecodeOptions};
+
+    #[test]
+    fn it_converts_dates() {
+        let date = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+        let param = Param::date("d", date);
+        assert_eq!(param.get_value(), "2020-02-29");
+        assert_eq!(param.to_naive_date(), Some(date));
+        let abi = ABI::only(param);
+        let decoded = ABI::decode(&abi.encode().unwrap(), true).unwrap();
+        assert_eq!(decoded.get_naive_date("d"), Ok(date));
+        let invalid = Param::Date {
+            name: "d".to_owned(),
+            year: 2021,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(invalid.to_naive_date(), None);
+    }
+
+    #[test]
+    fn it_converts_datetimes() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let at = offset.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+        let param = Param::datetime("at", &at);
+        assert_eq!(param.get_value(), "2021-07-01T12:00:00+02:00");
+        let utc = Param::datetime("at", &at.with_timezone(&Utc));
+        assert_eq!(utc.get_value(), "2021-07-01T10:00:00Z");
+
+        let abi = AbiBuilder::new().param(param).build().unwrap();
+        let data = abi.encode().unwrap();
+        let decoded = ABI::decode_with_options(&data, &DecodeOptions::all()).unwrap();
+        assert_eq!(decoded.get_datetime("at"), Ok(at));
+        // strict decoding keeps it as string32, that is still readable
+        let decoded = ABI::decode(&data, true).unwrap();
+        assert_eq!(decoded.get_datetime("at"), Ok(at));
+    }
+
+    #[test]
+    fn it_rejects_long_datetimes() {
+        let offset = FixedOffset::west_opt(3600).unwrap();
+        let at = offset
+            .with_ymd_and_hms(2021, 7, 1, 12, 0, 0)
+            .unwrap()
+            .checked_add_signed(chrono::Duration::nanoseconds(1))
+            .unwrap();
+        let param = Param::datetime("at", &at);
+        assert_eq!(param.get_value(), "2021-07-01T12:00:00.000000001-01:00");
+        let errors = AbiBuilder::new().param(param).build().unwrap_err().0;
+        assert_eq!(
+            errors,
+            vec![BuildError::ValueTooLong {
+                param: 0,
+                name: "at".to_owned()
+            }]
+        );
+    }
+
+    #[test]
+    fn it_converts_timestamps() {
+        let at = Utc.with_ymd_and_hms(2021, 7, 1, 12, 0, 0).unwrap();
+        let param = Param::timestamp("_ts", &at).unwrap();
+        assert_eq!(param.get_value(), "1625140800");
+        assert_eq!(param.to_timestamp(), Some(at));
+        let before = Utc.with_ymd_and_hms(1969, 12, 31, 0, 0, 0).unwrap();
+        assert_eq!(Param::timestamp("_ts", &before), None);
+        let abi = ABI::new(vec![
+            param,
+            Param::Uint256 {
+                name: "huge".to_owned(),
+                value: U256::MAX,
+            },
+        ]);
+        assert_eq!(abi.get_timestamp("_ts"), Ok(at));
+        assert_eq!(
+            abi.get_timestamp("huge"),
+            Err(ParamError::TypeMismatch {
+                name: "huge".to_owned(),
+                expected: "timestamp".to_owned(),
+                actual: "uint256".to_owned(),
+            })
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use crate::decode::str_to_date;
+use crate::prelude::*;
+use crate::ser::SerdeError;
+use crate::{Param, ABI};
+use core::convert::TryFrom;
+use ethereum_types::U256;
+use serde::de::value::{SeqDeserializer, StrDeserializer};
+use serde::de::{self, Deserialize, IntoDeserializer, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// converts Airnode ABI into the structure, parameters are matched to the fields by names.
+/// Integer fields accept `Uint256` and `Int256` parameters that fit into their range,
+/// `U256`, `I256` and `H160` fields accept parameters of the same type.
+/// Parameters without the matching field are ignored
+pub fn from_abi<'a, T: Deserialize<'a>>(abi: &'a ABI) -> Result<T, SerdeError> {
+    T::deserialize(AbiDeserializer { abi })
+}
+
+/// Deserializer of the top level structure from ABI
+struct AbiDeserializer<'a> {
+    abi: &'a ABI,
+}
+
+impl<'de> de::Deserializer<'de> for AbiDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_map(ParamsAccess {
+            params: self.abi.params.iter(),
+            value: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Access to the parameters as to the entries of the map
+struct ParamsAccess<'a> {
+    params: core::slice::Iter<'a, Param>,
+    /// parameter, which name was returned as a key
+    value: Option<&'a Param>,
+}
+
+impl<'de> de::MapAccess<'de> for ParamsAccess<'de> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, SerdeError> {
+        match self.params.next() {
+            Some(param) => {
+                self.value = Some(param);
+                let key: StrDeserializer<SerdeError> = param.get_name().into_deserializer();
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self.value.take() {
+            Some(param) => seed.deserialize(ParamDeserializer { param }),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+}
+
+/// Deserializer of the single parameter into the field of the structure
+struct ParamDeserializer<'a> {
+    param: &'a Param,
+}
+
+impl<'a> ParamDeserializer<'a> {
+    fn invalid(&self, reason: &str) -> SerdeError {
+        SerdeError::InvalidValue {
+            name: self.param.get_name().to_owned(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    /// returns textual representation of the parameter.
+    /// Numbers, bytes and addresses are represented the way
+    /// `U256`, `I256` and `H160` are deserialized from strings
+    fn text(&self) -> String {
+        match self.param {
+            Param::Address { value, .. } => format!("{:?}", value),
+            Param::Bytes { value, .. } => format!("0x{}", hex::encode(value)),
+            Param::Bytes32 { value, .. } => format!("0x{:064x}", value),
+            Param::Uint256 { value, .. } => format!("0x{:x}", value),
+            Param::String { value, .. } => value.clone(),
+            Param::String32 { value, .. } => value.clone(),
+            _ => self.param.get_value(),
+        }
+    }
+
+    fn visit_integer<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::Uint256 { value, .. } => {
+                if *value <= U256::from(u64::MAX) {
+                    visitor.visit_u64(value.as_u64())
+                } else if *value <= U256::from(u128::MAX) {
+                    visitor.visit_u128(value.as_u128())
+                } else {
+                    Err(self.invalid("integer is out of range"))
+                }
+            }
+            Param::Int256 { value, .. } => {
+                if let Ok(x) = i64::try_from(*value) {
+                    visitor.visit_i64(x)
+                } else if let Ok(x) = i128::try_from(*value) {
+                    visitor.visit_i128(x)
+                } else {
+                    Err(self.invalid("integer is out of range"))
+                }
+            }
+            _ => Err(self.invalid("integer is expected")),
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ParamDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::Bool { value, .. } => visitor.visit_bool(*value),
+            Param::Bytes { value, .. } => visitor.visit_borrowed_bytes(value),
+            Param::Int256 { .. } | Param::Uint256 { .. } => self.visit_integer(visitor),
+            Param::String { value, .. } => visitor.visit_borrowed_str(value),
+            Param::String32 { value, .. } => visitor.visit_borrowed_str(value),
+            _ => visitor.visit_string(self.text()),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::Bool { value, .. } => visitor.visit_bool(*value),
+            // booleans are stored as `String32` on the protocol level
+            Param::String32 { value, .. } if value == "true" => visitor.visit_bool(true),
+            Param::String32 { value, .. } if value == "false" => visitor.visit_bool(false),
+            _ => Err(self.invalid("boolean is expected")),
+        }
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_i128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+    fn deserialize_u128<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.visit_integer(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::String { value, .. } => visitor.visit_borrowed_str(value),
+            Param::String32 { value, .. } => visitor.visit_borrowed_str(value),
+            Param::Int256 { value, .. } => visitor.visit_string(value.to_string()),
+            _ => visitor.visit_string(self.text()),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_bytes<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::Bytes { value, .. } => visitor.visit_borrowed_bytes(value),
+            Param::Bytes32 { value, .. } => {
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                visitor.visit_bytes(&bytes)
+            }
+            Param::String { value, .. } => visitor.visit_borrowed_bytes(value.as_bytes()),
+            Param::String32 { value, .. } => visitor.visit_borrowed_bytes(value.as_bytes()),
+            _ => Err(self.invalid("bytes are expected")),
+        }
+    }
+
+    fn deserialize_byte_buf<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        self.deserialize_bytes(visitor)
+    }
+
+    /// parameters are never null, missing parameters are handled as missing fields
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// `Vec<u8>` is deserialized from `Bytes`
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::Bytes { value, .. } => {
+                visitor.visit_seq(SeqDeserializer::new(value.iter().cloned()))
+            }
+            _ => Err(self.invalid("sequence is expected")),
+        }
+    }
+
+    /// tuple of year, month and day is deserialized from `Date`
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        // dates are stored as `String32` on the protocol level
+        let date = match self.param {
+            Param::Date {
+                year, month, day, ..
+            } => Some((*year, *month, *day)),
+            Param::String32 { value, .. } => str_to_date(value),
+            _ => None,
+        };
+        match date {
+            Some((year, month, day)) => {
+                let parts = vec![year as i64, month as i64, day as i64];
+                visitor.visit_seq(SeqDeserializer::new(parts.into_iter()))
+            }
+            None => self.deserialize_seq(visitor),
+        }
+    }
+
+    /// unit variants of the enums are deserialized from their names
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, SerdeError> {
+        match self.param {
+            Param::String { value, .. } | Param::String32 { value, .. } => {
+                let variant: StrDeserializer<SerdeError> = value.as_str().into_deserializer();
+                visitor.visit_enum(variant)
+            }
+            _ => Err(self.invalid("string is expected")),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        f32 f64 char unit unit_struct tuple_struct map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{attr, to_abi, I256};
+    use ethereum_types::H160;
+    use hex_literal::hex;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Side {
+        Buy,
+        Sell,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Request {
+        #[serde(with = "attr::uint256")]
+        amount: U256,
+        count: u64,
+        delta: i32,
+        big: I256,
+        #[serde(with = "attr::address")]
+        wallet: H160,
+        active: bool,
+        label: String,
+        payload: Vec<u8>,
+        side: Side,
+        missing: Option<u8>,
+        #[serde(with = "attr::bytes32")]
+        id: U256,
+        #[serde(with = "attr::string32")]
+        from: String,
+        #[serde(with = "attr::date")]
+        date: (i32, u32, u32),
+    }
+
+    #[test]
+    fn it_deserializes_struct() {
+        let src = Request {
+            amount: U256::from_dec_str("1000000000000000000000000000000").unwrap(),
+            count: 5,
+            delta: -3,
+            big: I256::MIN,
+            wallet: hex!("4128922394c63a204dd98ea6fbd887780b78bb7d").into(),
+            active: true,
+            label: "hello".to_owned(),
+            payload: vec![1, 2, 3],
+            side: Side::Sell,
+            missing: None,
+            id: U256::from(0xabcd),
+            from: "ETH".to_owned(),
+            date: (2021, 7, 1),
+        };
+        let abi = to_abi(&src).unwrap();
+        let decoded = ABI::decode(&abi.encode().unwrap(), true).unwrap();
+        let res: Request = from_abi(&decoded).unwrap();
+        assert_eq!(res, src);
+    }
+
+    #[test]
+    fn it_checks_ranges() {
+        #[derive(Debug, Deserialize)]
+        struct Small {
+            #[allow(dead_code)]
+            value: u8,
+        }
+        let abi = ABI::only(Param::Uint256 {
+            name: "value".to_owned(),
+            value: U256::from(300),
+        });
+        assert!(from_abi::<Small>(&abi).is_err());
+        let abi = ABI::only(Param::Int256 {
+            name: "value".to_owned(),
+            value: I256::from(-1),
+        });
+        assert!(from_abi::<Small>(&abi).is_err());
+        assert!(from_abi::<Small>(&ABI::none()).is_err());
+    }
+}
@@ -0,0 +1,545 @@
+use crate::prelude::*;
+use crate::{Param, I256};
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum DecimalError {
+    #[error("invalid decimal number {0}")]
+    Invalid(String),
+    #[error("decimal number doesn't fit into 256 bits")]
+    Overflow,
+    #[error("negative decimal number cannot be unsigned")]
+    Negative,
+    #[error("decimal number has more fractional digits than allowed")]
+    Inexact,
+}
+
+/// Rounding of the digits that are dropped, when the number is scaled to an integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// towards zero (truncation)
+    Down,
+    /// away from zero
+    Up,
+    /// towards negative infinity
+    Floor,
+    /// towards positive infinity
+    Ceiling,
+    /// to the nearest, half away from zero
+    HalfUp,
+    /// to the nearest, half towards zero
+    HalfDown,
+    /// to the nearest, half to the even digit (banker's rounding)
+    HalfEven,
+    /// no rounding, `DecimalError::Inexact` if any non-zero digit would be dropped
+    Exact,
+}
+
+/// Exact decimal number: `digits * 10^exp`.
+/// It is used to apply `_times` multiplier without losing precision
+/// and to scale amounts like `12.345` into integers of the contracts:
+///
+/// ```
+/// use airnode_abi::{Decimal, Rounding};
+/// use ethereum_types::U256;
+///
+/// let amount: Decimal = "12.345".parse().unwrap();
+/// let wei = amount.to_u256_scaled(18, Rounding::Exact).unwrap();
+/// assert_eq!(wei, U256::from(12_345_000_000_000_000_000u128));
+/// assert_eq!(Decimal::from_u256_scaled(wei, 18).to_string(), "12.345");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decimal {
+    negative: bool,
+    /// decimal digits, most significant first, without leading zeros
+    digits: Vec<u8>,
+    exp: i64,
+}
+
+/// numbers with more digits in the integer part would overflow 256 bits anyway
+const MAX_INTEGER_DIGITS: i64 = 80;
+/// number of fractional digits of the inexact quotient
+const DIV_PRECISION: i64 = 18;
+
+impl Decimal {
+    fn new(negative: bool, digits: Vec<u8>, exp: i64) -> Self {
+        let start = match digits.iter().position(|d| *d != 0) {
+            Some(x) => x,
+            None => {
+                return Self {
+                    negative: false,
+                    digits: vec![],
+                    exp: 0,
+                }
+            }
+        };
+        // trailing zeros are moved into the exponent
+        let end = digits.iter().rposition(|d| *d != 0).unwrap() + 1;
+        Self {
+            negative,
+            digits: digits[start..end].to_vec(),
+            exp: exp.saturating_add((digits.len() - end) as i64),
+        }
+    }
+
+    /// constructor from the signed integer
+    pub fn from_i256(src: I256) -> Self {
+        Self::from_str(&src.to_string()).unwrap()
+    }
+
+    /// constructor from the unsigned integer
+    pub fn from_u256(src: U256) -> Self {
+        Self::from_str(&src.to_string()).unwrap()
+    }
+
+    /// returns `value * 10^-decimals`, i.e. the amount of tokens from the integer amount
+    /// of the smallest units
+    pub fn from_u256_scaled(value: U256, decimals: u32) -> Self {
+        Self::from_u256(value).mul_pow10(-(decimals as i64))
+    }
+
+    /// returns `value * 10^-decimals` for the signed integer
+    pub fn from_i256_scaled(value: I256, decimals: u32) -> Self {
+        Self::from_i256(value).mul_pow10(-(decimals as i64))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// exact product with `10^n`
+    pub fn mul_pow10(&self, n: i64) -> Self {
+        Self::new(
+            self.negative,
+            self.digits.clone(),
+            self.exp.saturating_add(n),
+        )
+    }
+
+    /// rounds the number to `scale` fractional digits
+    pub fn round(&self, scale: i64, rounding: Rounding) -> Result<Self, DecimalError> {
+        let len = self.digits.len() as i64;
+        // number of the digits to drop
+        let drop = scale.saturating_neg().saturating_sub(self.exp);
+        if self.is_zero() || drop <= 0 {
+            return Ok(self.clone());
+        }
+        let (kept, dropped) = if drop > len {
+            (&self.digits[..0], &self.digits[..])
+        } else {
+            self.digits.split_at((len - drop) as usize)
+        };
+        // comparison of the dropped part with the half of the last kept digit.
+        // The dropped part is never zero, as trailing zeros are in the exponent
+        let half = match dropped.first() {
+            _ if drop > len => Ordering::Less,
+            Some(5) if dropped[1..].iter().all(|d| *d == 0) => Ordering::Equal,
+            Some(5) => Ordering::Greater,
+            Some(d) => d.cmp(&5),
+            None => Ordering::Less,
+        };
+        let odd = kept.last().map(|d| d % 2 == 1).unwrap_or(false);
+        let away = match rounding {
+            Rounding::Down => false,
+            Rounding::Up => true,
+            Rounding::Floor => self.negative,
+            Rounding::Ceiling => !self.negative,
+            Rounding::HalfUp => half != Ordering::Less,
+            Rounding::HalfDown => half == Ordering::Greater,
+            Rounding::HalfEven => half == Ordering::Greater || (half == Ordering::Equal && odd),
+            Rounding::Exact => return Err(DecimalError::Inexact),
+        };
+        let mut digits = kept.to_vec();
+        if away {
+            let mut carry = true;
+            for d in digits.iter_mut().rev() {
+                *d += 1;
+                carry = *d == 10;
+                if !carry {
+                    break;
+                }
+                *d = 0;
+            }
+            if carry {
+                digits.insert(0, 1);
+            }
+        }
+        Ok(Self::new(self.negative, digits, scale.saturating_neg()))
+    }
+
+    /// returns `self * 10^decimals` as unsigned integer, rounding the fractional digits
+    pub fn to_u256_scaled(&self, decimals: u32, rounding: Rounding) -> Result<U256, DecimalError> {
+        let scaled = self.mul_pow10(decimals as i64).round(0, rounding)?;
+        if scaled.negative {
+            return Err(DecimalError::Negative);
+        }
+        scaled.to_u256().ok_or(DecimalError::Overflow)
+    }
+
+    /// returns `self * 10^decimals` as signed integer, rounding the fractional digits
+    pub fn to_i256_scaled(&self, decimals: u32, rounding: Rounding) -> Result<I256, DecimalError> {
+        let scaled = self.mul_pow10(decimals as i64).round(0, rounding)?;
+        scaled.to_i256().ok_or(DecimalError::Overflow)
+    }
+
+    /// exact product of two numbers
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut out = vec![0u32; self.digits.len() + other.digits.len()];
+        for (i, a) in self.digits.iter().rev().enumerate() {
+            for (j, b) in other.digits.iter().rev().enumerate() {
+                out[i + j] += (*a as u32) * (*b as u32);
+            }
+        }
+        for i in 0..out.len() {
+            let carry = out[i] / 10;
+            out[i] %= 10;
+            if carry > 0 {
+                out[i + 1] += carry;
+            }
+        }
+        let digits: Vec<u8> = out.iter().rev().map(|d| *d as u8).collect();
+        Self::new(
+            self.negative != other.negative,
+            digits,
+            self.exp.saturating_add(other.exp),
+        )
+    }
+
+    /// quotient of two numbers. It is exact, if the divisor is a power of 10,
+    /// otherwise it is truncated to 18 fractional digits.
+    /// Returns `None` on division by zero or if the divisor has too many digits
+    pub fn div(&self, other: &Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let negative = self.negative != other.negative;
+        let exp = self.exp.saturating_sub(other.exp);
+        if other.digits == [1] {
+            return Some(Self::new(negative, self.digits.clone(), exp));
+        }
+        // remainder of the long division must fit into u128
+        if other.digits.len() > 37 {
+            return None;
+        }
+        let divisor: u128 = other.digits.iter().fold(0, |acc, d| acc * 10 + *d as u128);
+        // scale the dividend, so the quotient has exactly DIV_PRECISION fractional digits
+        let shift = DIV_PRECISION.saturating_add(exp);
+        let mut dividend = self.digits.clone();
+        if shift >= 0 {
+            if shift > MAX_INTEGER_DIGITS * 2 {
+                return None;
+            }
+            dividend.resize(dividend.len() + shift as usize, 0);
+        } else {
+            let keep = (dividend.len() as i64).saturating_add(shift).max(0);
+            dividend.truncate(keep as usize);
+        }
+        let mut quotient = vec![];
+        let mut remainder: u128 = 0;
+        for d in dividend {
+            remainder = remainder * 10 + d as u128;
+            quotient.push((remainder / divisor) as u8);
+            remainder %= divisor;
+        }
+        Some(Self::new(negative, quotient, -DIV_PRECISION))
+    }
+
+    /// returns absolute value of the integer part (truncated towards zero),
+    /// or `None` if it doesn't fit into 256 bits
+    fn trunc_abs(&self) -> Option<U256> {
+        if self.is_zero() {
+            return Some(U256::zero());
+        }
+        let len = (self.digits.len() as i64).saturating_add(self.exp);
+        if len <= 0 {
+            return Some(U256::zero());
+        }
+        if len > MAX_INTEGER_DIGITS {
+            return None;
+        }
+        let mut s: String = self
+            .digits
+            .iter()
+            .take(len as usize)
+            .map(|d| (b'0' + d) as char)
+            .collect();
+        while (s.len() as i64) < len {
+            s.push('0');
+        }
+        U256::from_dec_str(&s).ok()
+    }
+
+    /// returns unsigned integer part (truncated towards zero)
+    pub fn to_u256(&self) -> Option<U256> {
+        let abs = self.trunc_abs()?;
+        if self.negative && !abs.is_zero() {
+            return None;
+        }
+        Some(abs)
+    }
+
+    /// returns signed integer part (truncated towards zero)
+    pub fn to_i256(&self) -> Option<I256> {
+        I256::from_sign_and_abs(self.negative, self.trunc_abs()?).ok()
+    }
+}
+
+/// plain decimal notation, without exponent
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        let digits: String = self.digits.iter().map(|d| (b'0' + d) as char).collect();
+        let sign = if self.negative { "-" } else { "" };
+        if self.exp >= 0 {
+            return write!(f, "{}{}{}", sign, digits, "0".repeat(self.exp as usize));
+        }
+        let frac_len = (-self.exp) as usize;
+        if frac_len >= digits.len() {
+            let zeros = "0".repeat(frac_len - digits.len());
+            write!(f, "{}0.{}{}", sign, zeros, digits)
+        } else {
+            let (int, frac) = digits.split_at(digits.len() - frac_len);
+            write!(f, "{}{}.{}", sign, int, frac)
+        }
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = DecimalError;
+
+    /// parses decimal string with optional sign, fraction and exponent, like `-1.5e-3`
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::parse(src).ok_or_else(|| DecimalError::Invalid(src.to_owned()))
+    }
+}
+
+impl Decimal {
+    fn parse(src: &str) -> Option<Self> {
+        let (negative, rest) = match src.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, src.strip_prefix('+').unwrap_or(src)),
+        };
+        let (mantissa, exp) = match rest.find(&['e', 'E'][..]) {
+            Some(pos) => {
+                let exp = &rest[pos + 1..];
+                let digits = exp.strip_prefix(&['+', '-'][..]).unwrap_or(exp);
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                (&rest[..pos], exp.parse::<i64>().ok()?)
+            }
+            None => (rest, 0),
+        };
+        let (int, frac) = match mantissa.find('.') {
+            Some(pos) => (&mantissa[..pos], &mantissa[pos + 1..]),
+            None => (mantissa, ""),
+        };
+        if int.is_empty() && frac.is_empty() {
+            return None;
+        }
+        let mut digits = vec![];
+        for c in int.chars().chain(frac.chars()) {
+            digits.push(c.to_digit(10)? as u8);
+        }
+        Some(Self::new(
+            negative,
+            digits,
+            exp.saturating_sub(frac.len() as i64),
+        ))
+    }
+}
+
+impl Param {
+    /// returns `Uint256` parameter with the decimal number, scaled by `10^decimals`,
+    /// like `12.345` with 18 decimals
+    pub fn uint256_decimal(
+        name: &str,
+        value: &Decimal,
+        decimals: u32,
+        rounding: Rounding,
+    ) -> Result<Self, DecimalError> {
+        Ok(Self::Uint256 {
+            name: name.to_owned(),
+            value: value.to_u256_scaled(decimals, rounding)?,
+        })
+    }
+
+    /// returns `Int256` parameter with the decimal number, scaled by `10^decimals`
+    pub fn int256_decimal(
+        name: &str,
+        value: &Decimal,
+        decimals: u32,
+        rounding: Rounding,
+    ) -> Result<Self, DecimalError> {
+        Ok(Self::Int256 {
+            name: name.to_owned(),
+            value: value.to_i256_scaled(decimals, rounding)?,
+        })
+    }
+
+    /// returns value of `Uint256` or `Int256` parameter, divided by `10^decimals`
+    pub fn to_decimal(&self, decimals: u32) -> Option<Decimal> {
+        match self {
+            Self::Uint256 { value, .. } => Some(Decimal::from_u256_scaled(*value, decimals)),
+            Self::Int256 { value, .. } => Some(Decimal::from_i256_scaled(*value, decimals)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(src: &str) -> Decimal {
+        Decimal::from_str(src).unwrap()
+    }
+
+    #[test]
+    fn it_parses() {
+        assert_eq!(dec("123.45").to_i256(), Some(I256::from(123)));
+        assert_eq!(dec("-123.45").to_i256(), Some(I256::from(-123)));
+        assert_eq!(dec("1.5e3").to_u256(), Some(U256::from(1500)));
+        assert_eq!(dec("15E-1").to_u256(), Some(U256::from(1)));
+        assert_eq!(dec(".5").to_u256(), Some(U256::zero()));
+        assert_eq!(dec("-0.5").to_u256(), Some(U256::zero()));
+        assert_eq!(dec("-1").to_u256(), None);
+        assert_eq!(dec("1e100").to_u256(), None);
+        assert_eq!(dec("1e-100").to_u256(), Some(U256::zero()));
+        for invalid in ["", ".", "-", "1e", "1.2.3", "0x10", "1 000", "e5"] {
+            assert!(Decimal::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn it_multiplies_exactly() {
+        let res = dec("0.1").mul(&dec("3"));
+        assert_eq!(res, dec("0.3"));
+        let res = dec("123.456789012345678901").mul(&dec("1e18"));
+        assert_eq!(res.to_u256(), Some(U256::from(123456789012345678901u128)));
+        let res = dec("-1.999").mul(&dec("1000"));
+        assert_eq!(res.to_i256(), Some(I256::from(-1999)));
+        let res = dec("-0.0001").mul(&dec("100"));
+        assert_eq!(res.to_i256(), Some(I256::ZERO));
+    }
+
+    #[test]
+    fn it_divides() {
+        let res = Decimal::from_i256(I256::from(301255)).div(&dec("100"));
+        assert_eq!(res.unwrap().to_string(), "3012.55");
+        let res = Decimal::from_u256(U256::from(5)).div(&dec("1e18"));
+        assert_eq!(res.unwrap().to_string(), "0.000000000000000005");
+        let res = dec("-1").div(&dec("3"));
+        assert_eq!(res.unwrap().to_string(), "-0.333333333333333333");
+        let res = dec("1000").div(&dec("0.5"));
+        assert_eq!(res.unwrap().to_string(), "2000");
+        assert_eq!(dec("1").div(&dec("0")), None);
+    }
+
+    #[test]
+    fn it_formats() {
+        for (src, expected) in [
+            ("0", "0"),
+            ("-0.0", "0"),
+            ("12.500", "12.5"),
+            ("1.2e3", "1200"),
+            ("-12e-4", "-0.0012"),
+            ("123456789", "123456789"),
+        ] {
+            assert_eq!(dec(src).to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn it_checks_ranges() {
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(dec(max).to_u256(), Some(U256::MAX));
+        assert_eq!(dec(max).mul(&dec("10")).to_u256(), None);
+        assert_eq!(dec(max).to_i256(), None);
+        assert_eq!(
+            dec("-57896044618658097711785492504343953926634992332820282019728792003956564819968")
+                .to_i256(),
+            Some(I256::MIN)
+        );
+    }
+
+    #[test]
+    fn it_rounds() {
+        let cases = vec![
+            // value, rounding, expected with 0 fractional digits
+            ("2.5", Rounding::Down, "2"),
+            ("2.5", Rounding::Up, "3"),
+            ("2.5", Rounding::HalfUp, "3"),
+            ("2.5", Rounding::HalfDown, "2"),
+            ("2.5", Rounding::HalfEven, "2"),
+            ("3.5", Rounding::HalfEven, "4"),
+            ("2.51", Rounding::HalfDown, "3"),
+            ("-2.5", Rounding::HalfUp, "-3"),
+            ("-2.1", Rounding::Floor, "-3"),
+            ("-2.9", Rounding::Ceiling, "-2"),
+            ("2.1", Rounding::Ceiling, "3"),
+            ("9.9", Rounding::Up, "10"),
+            ("0.001", Rounding::Up, "1"),
+            ("0.001", Rounding::HalfUp, "0"),
+            ("-0.001", Rounding::Down, "0"),
+            ("120", Rounding::Exact, "120"),
+        ];
+        for (src, rounding, expected) in cases {
+            let res = dec(src).round(0, rounding).unwrap();
+            assert_eq!(res.to_string(), expected, "{} {:?}", src, rounding);
+        }
+        assert_eq!(
+            dec("1.23456").round(2, Rounding::HalfEven).unwrap(),
+            dec("1.23")
+        );
+        assert_eq!(
+            dec("1.5").round(0, Rounding::Exact),
+            Err(DecimalError::Inexact)
+        );
+    }
+
+    #[test]
+    fn it_scales() {
+        let res = dec("12.345").to_u256_scaled(18, Rounding::Exact);
+        assert_eq!(res, Ok(U256::from(12_345_000_000_000_000_000u128)));
+        let res = dec("1.0000005").to_u256_scaled(6, Rounding::HalfEven);
+        assert_eq!(res, Ok(U256::from(1_000_000)));
+        let res = dec("-1.0000005").to_i256_scaled(6, Rounding::HalfUp);
+        assert_eq!(res, Ok(I256::from(-1_000_001)));
+        assert_eq!(
+            dec("-1").to_u256_scaled(0, Rounding::Exact),
+            Err(DecimalError::Negative)
+        );
+        assert_eq!(
+            dec("1e60").to_u256_scaled(18, Rounding::Exact),
+            Err(DecimalError::Overflow)
+        );
+        assert_eq!(
+            Decimal::from_str("12,5"),
+            Err(DecimalError::Invalid("12,5".to_owned()))
+        );
+        let amount = Decimal::from_u256_scaled(U256::from(1_500_000), 6);
+        assert_eq!(amount.to_string(), "1.5");
+    }
+
+    #[test]
+    fn it_makes_params() {
+        let param = Param::uint256_decimal("amount", &dec("0.1"), 18, Rounding::Exact).unwrap();
+        assert_eq!(param.get_value(), "100000000000000000");
+        assert_eq!(param.to_decimal(18), Some(dec("0.1")));
+        let param = Param::int256_decimal("delta", &dec("-2.5"), 2, Rounding::Exact).unwrap();
+        assert_eq!(param.get_value(), "-250");
+        assert_eq!(param.to_decimal(2).unwrap().to_string(), "-2.5");
+    }
+}
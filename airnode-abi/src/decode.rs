@@ -1,5 +1,8 @@
-use ethereum_types::{H160, U256, U512};
-use std::str::{self, FromStr, Utf8Error};
+use crate::encode::days_in_month;
+use crate::prelude::*;
+use crate::I256;
+use core::str::{self, Utf8Error};
+use ethereum_types::{H160, U256};
 
 /// decode chunk into string (it is right padded with zeros)
 pub fn chunk_to_str(src: U256) -> Result<String, Utf8Error> {
@@ -44,42 +47,79 @@ pub fn str_to_date(src: &str) -> Option<(i32, u32, u32)> {
     None
 }
 
-/// decode chunk into signed integer
-pub fn chunk_to_int(src: U256) -> (U256, i32) {
-    if src.bit(32 * 8 - 1) {
-        let a = U512::from_str("10000000000000000000000000000000000000000000000000000000000000000")
-            .unwrap()
-            - U256::from(src);
-        let U512(ref arr) = a;
-        let mut ret = [0; 4];
-        ret[0] = arr[0];
-        ret[1] = arr[1];
-        ret[2] = arr[2];
-        ret[3] = arr[3];
-        return (U256(ret), -1);
-    }
-    (src, 1)
+/// check whether the string is RFC3339 date and time, like `2021-07-01T12:00:00.5+02:00`
+pub fn str_to_datetime(src: &str) -> bool {
+    let bytes = src.as_bytes();
+    if bytes.len() < 20 || !src.is_ascii() {
+        return false;
+    }
+    let num = |from: usize, to: usize| digits_to_u32(&src[from..to]);
+    let (year, month, day) = match (num(0, 4), num(5, 7), num(8, 10)) {
+        (Some(y), Some(m), Some(d)) => (y as i32, m, d),
+        _ => return false,
+    };
+    if bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ') {
+        return false;
+    }
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return false;
+    }
+    let (hour, minute, second) = match (num(11, 13), num(14, 16), num(17, 19)) {
+        (Some(h), Some(m), Some(s)) => (h, m, s),
+        _ => return false,
+    };
+    // leap second is allowed
+    if bytes[13] != b':' || bytes[16] != b':' || hour > 23 || minute > 59 || second > 60 {
+        return false;
+    }
+    let mut rest = &src[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let digits = fraction.chars().take_while(|c| c.is_ascii_digit()).count();
+        if digits == 0 {
+            return false;
+        }
+        rest = &fraction[digits..];
+    }
+    match rest {
+        "Z" | "z" => true,
+        _ if rest.len() == 6 && (rest.starts_with('+') || rest.starts_with('-')) => {
+            match (digits_to_u32(&rest[1..3]), digits_to_u32(&rest[4..6])) {
+                (Some(h), Some(m)) => rest.as_bytes()[3] == b':' && h < 24 && m < 60,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
 }
 
-/// decode chunk into EVM address hash
-pub fn chunk_to_address(src: U256) -> H160 {
-    let mut s = String::from("");
-    let mut i = 19;
-    // compile a string of 20-byte hex value
-    loop {
-        let b = src.byte(i);
-        s.push_str(format!("{:02x}", b).as_str());
-        if i == 0 {
-            break;
-        }
-        i -= 1;
+/// parse unsigned number, that consists of decimal digits only
+fn digits_to_u32(src: &str) -> Option<u32> {
+    if src.chars().all(|c| c.is_ascii_digit()) {
+        src.parse().ok()
+    } else {
+        None
     }
-    H160::from_str(&s).unwrap()
 }
 
-/// decode chunk into vector of bytes
-pub fn chunk_to_vec(arr: &Vec<U256>, offset: usize, sz: usize) -> Vec<u8> {
+/// decode chunk into signed integer (two's complement)
+pub fn chunk_to_int(src: U256) -> I256 {
+    I256::from_raw(src)
+}
+
+/// decode chunk into EVM address hash (lower 20 bytes of the chunk)
+pub fn chunk_to_address(src: U256) -> H160 {
+    let mut bytes = [0u8; 32];
+    src.to_big_endian(&mut bytes);
+    H160::from_slice(&bytes[12..])
+}
+
+/// decode chunk into vector of bytes.
+/// `arr` must contain enough chunks to fit `sz` bytes, starting from `offset`
+pub fn chunk_to_vec(arr: &[U256], offset: usize, sz: usize) -> Vec<u8> {
     let mut out = Vec::with_capacity(sz);
+    if sz == 0 {
+        return out;
+    }
     let mut row_index = offset;
     let mut col_index = 0;
     let mut index = 0;
@@ -100,6 +140,12 @@ pub fn chunk_to_vec(arr: &Vec<U256>, offset: usize, sz: usize) -> Vec<u8> {
     out
 }
 
+/// decode bytes into array of 256 bits values (big endian).
+/// The length of `src` must be a multiple of 32, trailing bytes are ignored otherwise
+pub fn bytes_to_chunks(src: &[u8]) -> Vec<U256> {
+    src.chunks_exact(32).map(U256::from_big_endian).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -134,20 +180,58 @@ mod tests {
 
     #[test]
     fn it_can_decode_negative_int() {
-        let (res, sign) = chunk_to_int(
+        let res = chunk_to_int(
             hex!("ffffffffffffffffffffffffffffffffffffffffffffffff7538dcfb76180000").into(),
         );
-        assert_eq!(sign, -1);
-        assert_eq!(res, U256::from_dec_str("10000000000000000000").unwrap());
+        assert_eq!(res.signum(), -1);
+        assert_eq!(
+            res.unsigned_abs(),
+            U256::from_dec_str("10000000000000000000").unwrap()
+        );
+    }
+
+    #[test]
+    fn it_can_decode_bytes_into_chunks() {
+        let mut input = vec![0u8; 64];
+        input[31] = 1;
+        input[32] = 0x31;
+        let res = bytes_to_chunks(&input);
+        assert_eq!(
+            res,
+            vec![
+                U256::from(1),
+                hex!("3100000000000000000000000000000000000000000000000000000000000000").into(),
+            ]
+        );
     }
 
     #[test]
     fn it_can_decode_positive_int() {
-        let (res, sign) = chunk_to_int(
+        let res = chunk_to_int(
             hex!("0000000000000000000000000000000000000000000000000000001234567890").into(),
         );
-        assert_eq!(sign, 1);
-        assert_eq!(res, U256::from_str("1234567890").unwrap());
+        assert_eq!(res.signum(), 1);
+        assert_eq!(res, I256::from(0x1234567890i64));
+    }
+
+    #[test]
+    fn it_roundtrips_int_edges() {
+        let values = vec![
+            I256::MIN,
+            I256::MIN.checked_add(I256::ONE).unwrap(),
+            I256::from(i128::MIN),
+            I256::from(i64::MIN),
+            I256::MINUS_ONE,
+            I256::ZERO,
+            I256::ONE,
+            I256::from(i64::MAX),
+            I256::from(i128::MAX),
+            I256::MAX.checked_sub(I256::ONE).unwrap(),
+            I256::MAX,
+        ];
+        for v in values {
+            assert_eq!(chunk_to_int(crate::encode::int_chunk(v)), v);
+        }
     }
 
     #[test]
@@ -167,4 +251,32 @@ mod tests {
         let res = chunk_to_vec(&arr, 0, 3);
         assert_eq!(res, expected);
     }
+
+    #[test]
+    fn it_can_check_datetime() {
+        let valid = vec![
+            "2021-07-01T12:00:00Z",
+            "2020-02-29T23:59:60z",
+            "2021-07-01 12:00:00.125+02:00",
+            "2021-12-31T00:00:00-11:30",
+        ];
+        for src in valid {
+            assert!(str_to_datetime(src), "{}", src);
+        }
+        let invalid = vec![
+            "2021-07-01",
+            "2021-07-01T12:00:00",
+            "2021-02-29T12:00:00Z",
+            "2021-13-01T12:00:00Z",
+            "2021-07-00T12:00:00Z",
+            "2021-07-01T24:00:00Z",
+            "2021-07-01T12:00:00.Z",
+            "2021-07-01T12:00:00+2:00",
+            "2021-07-01T12:00:00+02:60",
+            "+021-07-01T12:00:00Z",
+        ];
+        for src in invalid {
+            assert!(!str_to_datetime(src), "{}", src);
+        }
+    }
 }
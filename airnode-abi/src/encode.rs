@@ -1,6 +1,7 @@
-use ethereum_types::{H160, U256, U512};
-use std::convert::TryInto;
-use std::str::FromStr;
+use crate::prelude::*;
+use crate::I256;
+use core::convert::TryInto;
+use ethereum_types::{H160, U256};
 
 /// converts array of bytes into fixed array of 32 or panic
 /// Fix of the size must be at compile time! use carefully, this function panics.
@@ -35,7 +36,8 @@ fn is_leap_year(year: i32) -> bool {
     return (year % 4 == 0) && (year % 100 != 0 || year % 400 == 0);
 }
 
-fn days_in_month(year: i32, month: u32) -> u32 {
+/// returns number of days in the month of the given year
+pub fn days_in_month(year: i32, month: u32) -> u32 {
     return match month {
         2 => {
             if is_leap_year(year) {
@@ -49,20 +51,24 @@ fn days_in_month(year: i32, month: u32) -> u32 {
     };
 }
 
-/// convert date into chunk as string32
-pub fn date_chunk(year: i32, month: u32, day: u32) -> Result<U256, crate::EncodingError> {
+/// check that the date exists in the calendar
+pub fn validate_date(year: i32, month: u32, day: u32) -> Result<(), crate::EncodingError> {
     if year <= 0 {
         return Err(crate::EncodingError::InvalidYear);
     }
-    if month > 12 {
+    if month == 0 || month > 12 {
         return Err(crate::EncodingError::InvalidMonth);
     }
-    if day > days_in_month(year, month) {
+    if day == 0 || day > days_in_month(year, month) {
         return Err(crate::EncodingError::InvalidDay);
     }
-    let s = format!("{:04}-{:02}-{:02}", year, month, day);
-    println!("date_chunk={}", &s);
-    str_chunk32(&s)
+    Ok(())
+}
+
+/// convert date into chunk as string32
+pub fn date_chunk(year: i32, month: u32, day: u32) -> Result<U256, crate::EncodingError> {
+    validate_date(year, month, day)?;
+    str_chunk32(&format!("{:04}-{:02}-{:02}", year, month, day))
 }
 
 /// convert string of unlimited length into array of 256 bits
@@ -90,25 +96,9 @@ pub fn chunks(src: &[u8]) -> Vec<U256> {
     out
 }
 
-/// the first bit of `src` is ignored. sign must be either 1 or -1
-pub fn int_chunk(src: U256, sign: i32) -> U256 {
-    if sign != 1 && sign != -1 {
-        panic!("only sign of 1 or -1 is accepted")
-    }
-    if sign == 1 {
-        U256::from(src)
-    } else {
-        let a = U512::from_str("10000000000000000000000000000000000000000000000000000000000000000")
-            .unwrap()
-            - U256::from(src);
-        let U512(ref arr) = a;
-        let mut ret = [0; 4];
-        ret[0] = arr[0];
-        ret[1] = arr[1];
-        ret[2] = arr[2];
-        ret[3] = arr[3];
-        U256(ret)
-    }
+/// converts signed integer into 256 bits of two's complement
+pub fn int_chunk(src: I256) -> U256 {
+    src.into_raw()
 }
 
 /// converts EVM address into 256 bits
@@ -116,20 +106,53 @@ pub fn address_chunk(src: H160) -> U256 {
     U256::from(src.as_bytes())
 }
 
+/// converts array of 256 bits values into bytes, 32 bytes per value (big endian)
+pub fn chunks_to_bytes(src: &[U256]) -> Vec<u8> {
+    let mut out = vec![0u8; src.len() * 32];
+    for (chunk, bytes) in src.iter().zip(out.chunks_exact_mut(32)) {
+        chunk.to_big_endian(bytes);
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ethereum_types::U256;
     use hex_literal::hex;
+    use std::str::FromStr;
 
     #[test]
     fn it_can_encode_negative_int256() {
-        let encoded = int_chunk(U256::from_dec_str("10000000000000000000").unwrap(), -1);
+        let encoded = int_chunk(I256::from_str("-10000000000000000000").unwrap());
         let expected: U256 =
             hex!("ffffffffffffffffffffffffffffffffffffffffffffffff7538dcfb76180000").into();
         assert_eq!(encoded, expected);
     }
 
+    #[test]
+    fn it_can_encode_int256_edges() {
+        assert_eq!(int_chunk(I256::ZERO), U256::zero());
+        assert_eq!(int_chunk(I256::MINUS_ONE), U256::MAX);
+        assert_eq!(
+            int_chunk(I256::MIN),
+            hex!("8000000000000000000000000000000000000000000000000000000000000000").into()
+        );
+        assert_eq!(
+            int_chunk(I256::MAX),
+            hex!("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff").into()
+        );
+    }
+
+    #[test]
+    fn it_converts_chunks_to_bytes() {
+        let res = chunks_to_bytes(&[U256::from(1), U256::MAX]);
+        assert_eq!(res.len(), 64);
+        assert_eq!(res[31], 1);
+        assert!(res[..31].iter().all(|b| *b == 0));
+        assert!(res[32..].iter().all(|b| *b == 0xff));
+    }
+
     #[test]
     fn it_can_rpad32() {
         let padded = rpad32(&vec![0x31, 0x75]);
@@ -0,0 +1,360 @@
+use crate::prelude::*;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use ethereum_types::U256;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum I256Error {
+    #[error("invalid integer {0}")]
+    InvalidInteger(String),
+    #[error("integer overflow")]
+    Overflow,
+}
+
+/// Signed 256 bits integer, stored as two's complement (the way EVM stores `int256`)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct I256(U256);
+
+const SIGN_BIT: u64 = 0x8000_0000_0000_0000;
+
+impl I256 {
+    /// the smallest value, -2^255
+    pub const MIN: Self = Self(U256([0, 0, 0, SIGN_BIT]));
+    /// the largest value, 2^255 - 1
+    pub const MAX: Self = Self(U256([u64::MAX, u64::MAX, u64::MAX, !SIGN_BIT]));
+    pub const ZERO: Self = Self(U256([0, 0, 0, 0]));
+    pub const ONE: Self = Self(U256([1, 0, 0, 0]));
+    pub const MINUS_ONE: Self = Self(U256([u64::MAX, u64::MAX, u64::MAX, u64::MAX]));
+
+    /// constructor from two's complement representation
+    pub fn from_raw(raw: U256) -> Self {
+        Self(raw)
+    }
+
+    /// returns two's complement representation
+    pub fn into_raw(self) -> U256 {
+        self.0
+    }
+
+    /// constructor from the sign and the absolute value,
+    /// fails if the value doesn't fit into 256 bits
+    pub fn from_sign_and_abs(negative: bool, abs: U256) -> Result<Self, I256Error> {
+        if negative {
+            if abs > Self::MIN.0 {
+                return Err(I256Error::Overflow);
+            }
+            Ok(Self(twos_complement(abs)))
+        } else {
+            if abs > Self::MAX.0 {
+                return Err(I256Error::Overflow);
+            }
+            Ok(Self(abs))
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// returns -1, 0 or 1 depending on the sign of the value
+    pub fn signum(&self) -> i32 {
+        if self.is_negative() {
+            -1
+        } else if self.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// returns absolute value. It is defined for `I256::MIN` as well
+    pub fn unsigned_abs(&self) -> U256 {
+        if self.is_negative() {
+            twos_complement(self.0)
+        } else {
+            self.0
+        }
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        if self == Self::MIN {
+            return None;
+        }
+        Some(Self(twos_complement(self.0)))
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (res, _) = self.0.overflowing_add(other.0);
+        let res = Self(res);
+        if self.is_negative() == other.is_negative() && res.is_negative() != self.is_negative() {
+            return None;
+        }
+        Some(res)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (res, _) = self.0.overflowing_sub(other.0);
+        let res = Self(res);
+        if self.is_negative() != other.is_negative() && res.is_negative() != self.is_negative() {
+            return None;
+        }
+        Some(res)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let abs = self.unsigned_abs().checked_mul(other.unsigned_abs())?;
+        Self::from_sign_and_abs(self.is_negative() != other.is_negative(), abs).ok()
+    }
+
+    /// division, rounding towards zero (like in Solidity)
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let abs = self.unsigned_abs() / other.unsigned_abs();
+        Self::from_sign_and_abs(self.is_negative() != other.is_negative(), abs).ok()
+    }
+
+    /// remainder of the division, it has the sign of the dividend (like in Solidity)
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        let abs = self.unsigned_abs() % other.unsigned_abs();
+        Self::from_sign_and_abs(self.is_negative(), abs).ok()
+    }
+}
+
+/// negates two's complement representation
+fn twos_complement(src: U256) -> U256 {
+    (!src).overflowing_add(U256::one()).0
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // flipping the sign bit makes the order of raw values the same as signed
+        (self.0 ^ Self::MIN.0).cmp(&(other.0 ^ Self::MIN.0))
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for I256 {
+                fn from(src: $t) -> Self {
+                    let abs = U256::from(src.unsigned_abs());
+                    if src < 0 {
+                        Self(twos_complement(abs))
+                    } else {
+                        Self(abs)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, i128);
+
+impl TryFrom<I256> for i64 {
+    type Error = I256Error;
+
+    fn try_from(src: I256) -> Result<Self, Self::Error> {
+        let v = i128::try_from(src)?;
+        i64::try_from(v).map_err(|_| I256Error::Overflow)
+    }
+}
+
+impl TryFrom<I256> for i128 {
+    type Error = I256Error;
+
+    fn try_from(src: I256) -> Result<Self, Self::Error> {
+        let abs = src.unsigned_abs();
+        if abs.bits() > 128 {
+            return Err(I256Error::Overflow);
+        }
+        let abs = abs.as_u128();
+        if src.is_negative() {
+            if abs > i128::MIN.unsigned_abs() {
+                return Err(I256Error::Overflow);
+            }
+            Ok((abs as i128).wrapping_neg())
+        } else {
+            i128::try_from(abs).map_err(|_| I256Error::Overflow)
+        }
+    }
+}
+
+impl FromStr for I256 {
+    type Err = I256Error;
+
+    /// parses decimal string with optional sign
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let invalid = || I256Error::InvalidInteger(src.to_owned());
+        let (negative, digits) = match src.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, src.strip_prefix('+').unwrap_or(src)),
+        };
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let abs = U256::from_dec_str(digits).map_err(|_| I256Error::Overflow)?;
+        Self::from_sign_and_abs(negative, abs)
+    }
+}
+
+impl fmt::Display for I256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_negative() {
+            write!(f, "-{}", self.unsigned_abs())
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+impl fmt::Debug for I256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+/// serialized as decimal string, as JSON numbers cannot hold 256 bits.
+/// It is marked as `Int256` for `to_abi`, other serializers see only the string
+impl Serialize for I256 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(crate::attr::INT256_TOKEN, &format_args!("{}", self))
+    }
+}
+
+impl<'de> Deserialize<'de> for I256 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_primitives() {
+        for v in [0i128, 1, -1, 1000, -1000, i128::MAX, i128::MIN] {
+            let i = I256::from(v);
+            assert_eq!(i128::try_from(i).unwrap(), v);
+            assert_eq!(i.to_string(), v.to_string());
+        }
+        assert_eq!(i64::try_from(I256::from(i64::MIN)).unwrap(), i64::MIN);
+        assert_eq!(
+            i64::try_from(I256::from(i64::MAX as i128 + 1)),
+            Err(I256Error::Overflow)
+        );
+        assert_eq!(i128::try_from(I256::MIN), Err(I256Error::Overflow));
+    }
+
+    #[test]
+    fn it_parses_and_formats() {
+        let min = "-57896044618658097711785492504343953926634992332820282019728792003956564819968";
+        let max = "57896044618658097711785492504343953926634992332820282019728792003956564819967";
+        assert_eq!(I256::from_str(min).unwrap(), I256::MIN);
+        assert_eq!(I256::from_str(max).unwrap(), I256::MAX);
+        assert_eq!(I256::MIN.to_string(), min);
+        assert_eq!(I256::MAX.to_string(), max);
+        assert_eq!(I256::from_str("+42").unwrap(), I256::from(42));
+        assert_eq!(I256::from_str("-0").unwrap(), I256::ZERO);
+        assert_eq!(
+            I256::from_str(
+                "57896044618658097711785492504343953926634992332820282019728792003956564819968"
+            ),
+            Err(I256Error::Overflow)
+        );
+        assert!(I256::from_str("").is_err());
+        assert!(I256::from_str("-").is_err());
+        assert!(I256::from_str("1e5").is_err());
+        assert!(I256::from_str("0x10").is_err());
+    }
+
+    #[test]
+    fn it_compares() {
+        let mut values = vec![
+            I256::MAX,
+            I256::ONE,
+            I256::MIN,
+            I256::ZERO,
+            I256::MINUS_ONE,
+            I256::from(-1000),
+        ];
+        values.sort();
+        assert_eq!(
+            values,
+            vec![
+                I256::MIN,
+                I256::from(-1000),
+                I256::MINUS_ONE,
+                I256::ZERO,
+                I256::ONE,
+                I256::MAX
+            ]
+        );
+    }
+
+    #[test]
+    fn it_does_checked_arithmetic() {
+        assert_eq!(
+            I256::from(2).checked_add(I256::from(-5)),
+            Some(I256::from(-3))
+        );
+        assert_eq!(I256::MAX.checked_add(I256::ONE), None);
+        assert_eq!(I256::MIN.checked_add(I256::MINUS_ONE), None);
+        assert_eq!(I256::MIN.checked_sub(I256::ONE), None);
+        assert_eq!(I256::MAX.checked_sub(I256::MINUS_ONE), None);
+        assert_eq!(
+            I256::ZERO.checked_sub(I256::MAX),
+            Some(I256::MIN.checked_add(I256::ONE).unwrap())
+        );
+        assert_eq!(
+            I256::from(-7).checked_mul(I256::from(6)),
+            Some(I256::from(-42))
+        );
+        assert_eq!(I256::MIN.checked_mul(I256::MINUS_ONE), None);
+        assert_eq!(I256::MIN.checked_mul(I256::ONE), Some(I256::MIN));
+        assert_eq!(
+            I256::from(-7).checked_div(I256::from(2)),
+            Some(I256::from(-3))
+        );
+        assert_eq!(
+            I256::from(-7).checked_rem(I256::from(2)),
+            Some(I256::from(-1))
+        );
+        assert_eq!(I256::MIN.checked_div(I256::MINUS_ONE), None);
+        assert_eq!(I256::ONE.checked_div(I256::ZERO), None);
+        assert_eq!(I256::MIN.checked_neg(), None);
+        assert_eq!(
+            I256::MAX.checked_neg().unwrap().checked_sub(I256::ONE),
+            Some(I256::MIN)
+        );
+    }
+
+    #[test]
+    fn it_serializes_as_string() {
+        let v = I256::from(-1000);
+        let s = serde_json::to_string(&v).unwrap();
+        assert_eq!(s, "\"-1000\"");
+        assert_eq!(serde_json::from_str::<I256>(&s).unwrap(), v);
+    }
+}
@@ -0,0 +1,464 @@
+use crate::decode::{chunk_to_address, chunk_to_str, str_to_date, str_to_datetime};
+use crate::prelude::*;
+use crate::{Param, I256};
+use core::fmt;
+use core::str::FromStr;
+use ethereum_types::U256;
+
+/// 32 bytes value of `Bytes32` (`b`) or `String32` (`s`) parameter, that is being decoded
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bytes32Value {
+    /// raw value
+    pub value: U256,
+    /// schema character of the parameter, `b` or `s`
+    pub schema_char: char,
+    text: Option<String>,
+}
+
+impl Bytes32Value {
+    pub fn new(value: U256, schema_char: char) -> Self {
+        Self {
+            value,
+            schema_char,
+            text: chunk_to_str(value).ok(),
+        }
+    }
+
+    /// returns the value as a string, if it is a valid UTF-8 string (right padded with zeros)
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+/// Interpretation of the 32 bytes value as a parameter of the richer type.
+/// Interpreters are tried in the order of `DecodeOptions`, the first successful one wins.
+pub trait Bytes32Interpreter {
+    /// name of the interpreter, that is used to remove it from the options
+    fn name(&self) -> &str;
+    /// returns parameter, if the value could be interpreted
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param>;
+}
+
+/// `true` or `false` strings into `Bool`
+pub struct BoolInterpreter;
+
+impl Bytes32Interpreter for BoolInterpreter {
+    fn name(&self) -> &str {
+        "bool"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        let value = match value.text()? {
+            "true" => true,
+            "false" => false,
+            _ => return None,
+        };
+        Some(Param::Bool {
+            name: name.to_owned(),
+            value,
+        })
+    }
+}
+
+/// `YYYY-MM-DD` strings into `Date`
+pub struct DateInterpreter;
+
+impl Bytes32Interpreter for DateInterpreter {
+    fn name(&self) -> &str {
+        "date"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        let (year, month, day) = str_to_date(value.text()?)?;
+        Some(Param::Date {
+            name: name.to_owned(),
+            year,
+            month,
+            day,
+        })
+    }
+}
+
+/// RFC3339 strings, like `2021-07-01T12:00:00Z`, into `DateTime`
+pub struct DateTimeInterpreter;
+
+impl Bytes32Interpreter for DateTimeInterpreter {
+    fn name(&self) -> &str {
+        "datetime"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        let text = value.text()?;
+        if !str_to_datetime(text) {
+            return None;
+        }
+        Some(Param::DateTime {
+            name: name.to_owned(),
+            value: text.to_owned(),
+        })
+    }
+}
+
+/// `Bytes32` values with valid non-empty UTF-8 string into `String32`
+pub struct String32Interpreter;
+
+impl Bytes32Interpreter for String32Interpreter {
+    fn name(&self) -> &str {
+        "string32"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        match value.text()? {
+            "" => None,
+            text => Some(Param::String32 {
+                name: name.to_owned(),
+                value: text.to_owned(),
+            }),
+        }
+    }
+}
+
+/// `Bytes32` values with the address in the lower 20 bytes (and zeros in the upper 12 bytes)
+/// into `Address`
+pub struct AddressInterpreter;
+
+impl Bytes32Interpreter for AddressInterpreter {
+    fn name(&self) -> &str {
+        "address"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        if value.schema_char != 'b' || value.value.is_zero() || value.value.bits() > 160 {
+            return None;
+        }
+        Some(Param::Address {
+            name: name.to_owned(),
+            value: chunk_to_address(value.value),
+        })
+    }
+}
+
+/// decimal integer strings, like `-123`, into `Int256` (if negative) or `Uint256`
+pub struct NumberInterpreter;
+
+impl Bytes32Interpreter for NumberInterpreter {
+    fn name(&self) -> &str {
+        "number"
+    }
+
+    fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        let text = value.text()?;
+        let name = name.to_owned();
+        match text.strip_prefix('-') {
+            Some(digits) if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) => {
+                let value = I256::from_str(text).ok()?;
+                Some(Param::Int256 { name, value })
+            }
+            None if !text.is_empty() && text.chars().all(|c| c.is_ascii_digit()) => {
+                let value = U256::from_dec_str(text).ok()?;
+                Some(Param::Uint256 { name, value })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Options of ABI decoding.
+///
+/// - `legacy` flag enables compatibility with the legacy versions of this library,
+///   which used `B` schema character for `String32`.
+/// - interpreters of `Bytes32` and `String32` values are tried in the given order,
+///   values that are not interpreted are decoded as `Bytes32` and `String32`.
+///
+/// `DecodeOptions::default()` reproduces the non-strict decoding (bool, date, string32),
+/// `DecodeOptions::strict()` has no interpreters.
+pub struct DecodeOptions {
+    legacy: bool,
+    interpreters: Vec<Box<dyn Bytes32Interpreter>>,
+}
+
+impl DecodeOptions {
+    /// options without interpreters: values are decoded exactly as their schema characters say
+    pub fn strict() -> Self {
+        Self {
+            legacy: false,
+            interpreters: vec![],
+        }
+    }
+
+    /// options with all built-in interpreters:
+    /// bool, date, datetime, address, number, string32
+    pub fn all() -> Self {
+        Self::strict()
+            .with(BoolInterpreter)
+            .with(DateInterpreter)
+            .with(DateTimeInterpreter)
+            .with(AddressInterpreter)
+            .with(NumberInterpreter)
+            .with(String32Interpreter)
+    }
+
+    /// enables compatibility with the legacy versions of this library
+    pub fn legacy(mut self, legacy: bool) -> Self {
+        self.legacy = legacy;
+        self
+    }
+
+    /// adds interpreter to the end of the list
+    pub fn with<T: Bytes32Interpreter + 'static>(mut self, interpreter: T) -> Self {
+        self.interpreters.push(Box::new(interpreter));
+        self
+    }
+
+    /// adds built-in interpreter by its name:
+    /// bool, date, datetime, address, number or string32.
+    /// Returns `None` if there is no interpreter with that name
+    pub fn with_builtin(self, name: &str) -> Option<Self> {
+        match name {
+            "bool" => Some(self.with(BoolInterpreter)),
+            "date" => Some(self.with(DateInterpreter)),
+            "datetime" => Some(self.with(DateTimeInterpreter)),
+            "address" => Some(self.with(AddressInterpreter)),
+            "number" => Some(self.with(NumberInterpreter)),
+            "string32" => Some(self.with(String32Interpreter)),
+            _ => None,
+        }
+    }
+
+    /// removes interpreter by its name
+    pub fn without(mut self, name: &str) -> Self {
+        self.interpreters.retain(|x| x.name() != name);
+        self
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.legacy
+    }
+
+    /// returns names of the interpreters, in the order they are tried
+    pub fn interpreter_names(&self) -> Vec<&str> {
+        self.interpreters.iter().map(|x| x.name()).collect()
+    }
+
+    /// returns parameter, produced by the first successful interpreter
+    pub(crate) fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+        self.interpreters
+            .iter()
+            .find_map(|x| x.interpret(name, value))
+    }
+}
+
+impl Default for DecodeOptions {
+    fn default() -> Self {
+        Self::strict()
+            .with(BoolInterpreter)
+            .with(DateInterpreter)
+            .with(String32Interpreter)
+    }
+}
+
+impl fmt::Debug for DecodeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeOptions")
+            .field("legacy", &self.legacy)
+            .field("interpreters", &self.interpreter_names())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ABI;
+    use ethereum_types::H160;
+    use hex_literal::hex;
+
+    /// decodes single parameter of the given schema character
+    fn decode(ch: char, value: U256, options: &DecodeOptions) -> Param {
+        let schema = format!("1{}", ch);
+        let data = vec![
+            crate::encode::str_chunk32(&schema).unwrap(),
+            crate::encode::str_chunk32("p").unwrap(),
+            value,
+        ];
+        let res = ABI::decode_with_options(&data, options).unwrap();
+        res.params[0].clone()
+    }
+
+    fn text(src: &str) -> U256 {
+        crate::encode::str_chunk32(src).unwrap()
+    }
+
+    /// interprets upper case strings of 3-5 letters as tickers
+    struct TickerInterpreter;
+
+    impl Bytes32Interpreter for TickerInterpreter {
+        fn name(&self) -> &str {
+            "ticker"
+        }
+
+        fn interpret(&self, name: &str, value: &Bytes32Value) -> Option<Param> {
+            let text = value.text()?;
+            if text.len() < 3 || text.len() > 5 || !text.chars().all(|c| c.is_ascii_uppercase()) {
+                return None;
+            }
+            Some(Param::String32 {
+                name: name.to_owned(),
+                value: format!("${}", text),
+            })
+        }
+    }
+
+    #[test]
+    fn it_keeps_default_heuristics() {
+        let options = DecodeOptions::default();
+        assert_eq!(
+            options.interpreter_names(),
+            vec!["bool", "date", "string32"]
+        );
+        assert_eq!(
+            decode('b', text("true"), &options),
+            Param::Bool {
+                name: "p".to_owned(),
+                value: true
+            }
+        );
+        assert_eq!(
+            decode('s', text("2021-01-19"), &options),
+            Param::Date {
+                name: "p".to_owned(),
+                year: 2021,
+                month: 1,
+                day: 19
+            }
+        );
+        assert_eq!(
+            decode('b', text("ETH"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "ETH".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn it_keeps_ascii_bytes32_in_strict_mode() {
+        // template id that happens to consist of ASCII characters
+        let value: U256 =
+            hex!("3132333435363738393031323334353637383930313233343536373839303132").into();
+        let options = DecodeOptions::strict();
+        assert_eq!(
+            decode('b', value, &options),
+            Param::Bytes32 {
+                name: "p".to_owned(),
+                value
+            }
+        );
+        assert_eq!(
+            decode('s', text("true"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "true".to_owned()
+            }
+        );
+        // string32 interpreter is not the only way to keep it
+        let options = DecodeOptions::all().without("string32").without("number");
+        assert_eq!(
+            decode('b', value, &options),
+            Param::Bytes32 {
+                name: "p".to_owned(),
+                value
+            }
+        );
+    }
+
+    #[test]
+    fn it_interprets_extended_types() {
+        let options = DecodeOptions::all();
+        assert_eq!(
+            decode('s', text("2021-07-01T12:00:00Z"), &options),
+            Param::DateTime {
+                name: "p".to_owned(),
+                value: "2021-07-01T12:00:00Z".to_owned()
+            }
+        );
+        let address: H160 = hex!("4128922394C63A204Dd98ea6fbd887780b78bb7d").into();
+        assert_eq!(
+            decode('b', crate::encode::address_chunk(address), &options),
+            Param::Address {
+                name: "p".to_owned(),
+                value: address
+            }
+        );
+        // address could not be embedded into string32
+        assert_eq!(decode('s', text("abc"), &options).get_char(), 's');
+        assert_eq!(
+            decode('s', text("-42"), &options),
+            Param::Int256 {
+                name: "p".to_owned(),
+                value: I256::from(-42)
+            }
+        );
+        assert_eq!(
+            decode('b', text("1000"), &options),
+            Param::Uint256 {
+                name: "p".to_owned(),
+                value: U256::from(1000)
+            }
+        );
+        assert_eq!(
+            decode('s', text("1.5"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "1.5".to_owned()
+            }
+        );
+    }
+
+    #[test]
+    fn it_uses_custom_interpreters_in_order() {
+        let options = DecodeOptions::strict()
+            .with(TickerInterpreter)
+            .with(String32Interpreter);
+        assert_eq!(
+            decode('b', text("ETH"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "$ETH".to_owned()
+            }
+        );
+        assert_eq!(
+            decode('b', text("eth"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "eth".to_owned()
+            }
+        );
+        let options = DecodeOptions::strict()
+            .with(String32Interpreter)
+            .with(TickerInterpreter);
+        assert_eq!(
+            decode('b', text("ETH"), &options),
+            Param::String32 {
+                name: "p".to_owned(),
+                value: "ETH".to_owned()
+            }
+        );
+        let options = options.without("string32");
+        assert_eq!(options.interpreter_names(), vec!["ticker"]);
+    }
+
+    #[test]
+    fn it_encodes_datetime_as_string32() {
+        let param = Param::DateTime {
+            name: "p".to_owned(),
+            value: "2021-07-01T12:00:00+02:00".to_owned(),
+        };
+        let abi = ABI::only(param.clone());
+        assert_eq!(abi.schema, "1s");
+        let res = ABI::decode_with_options(&abi.encode().unwrap(), &DecodeOptions::all());
+        assert_eq!(res.unwrap(), abi);
+        let res = ABI::decode(&abi.encode().unwrap(), true).unwrap();
+        assert_eq!(res.params[0].get_value(), param.get_value());
+    }
+}
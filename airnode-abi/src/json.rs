@@ -0,0 +1,315 @@
+use crate::prelude::*;
+use crate::{Param, ABI, I256};
+use core::str::FromStr;
+use ethereum_types::{H160, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum JsonError {
+    #[error("invalid JSON {0}")]
+    InvalidJson(String),
+    #[error("list of parameters is expected")]
+    NotAnArray,
+    #[error("parameter {0}: object is expected")]
+    NotAnObject(usize),
+    #[error("parameter {param}: field {field} is missing")]
+    MissingField { param: usize, field: String },
+    #[error("parameter {param}: unknown type {kind}")]
+    UnknownType { param: usize, kind: String },
+    #[error("parameter {param}: invalid value, {reason}")]
+    InvalidValue { param: usize, reason: String },
+}
+
+/// converts bytes into 0x-prefixed hex string
+fn to_hex(src: &[u8]) -> String {
+    format!("0x{}", hex::encode(src))
+}
+
+impl Param {
+    /// returns name of the type of the parameter, as it is used by Airnode tooling.
+    /// `Bool` and `Date` are represented as `string32`
+    pub fn get_type(&self) -> &'static str {
+        match &self {
+            Self::Address { name: _, value: _ } => "address",
+            Self::Bytes { name: _, value: _ } => "bytes",
+            Self::Bytes32 { name: _, value: _ } => "bytes32",
+            Self::Int256 { name: _, value: _ } => "int256",
+            Self::String { name: _, value: _ } => "string",
+            Self::Uint256 { name: _, value: _ } => "uint256",
+            _ => "string32",
+        }
+    }
+
+    /// returns parameter in the JSON format of Airnode tooling:
+    /// `{"type":"string32","name":"from","value":"ETH"}`.
+    /// Integers are decimal strings, bytes and addresses are 0x-prefixed hex strings
+    pub fn to_json(&self) -> Value {
+        let value = match &self {
+            Self::Address { name: _, value } => to_hex(value.as_bytes()),
+            Self::Bytes { name: _, value } => to_hex(value),
+            Self::Bytes32 { name: _, value } => {
+                let mut bytes = [0u8; 32];
+                value.to_big_endian(&mut bytes);
+                to_hex(&bytes)
+            }
+            Self::Uint256 { name: _, value } => value.to_string(),
+            _ => self.get_value(),
+        };
+        json!({
+            "type": self.get_type(),
+            "name": self.get_name(),
+            "value": value,
+        })
+    }
+
+    /// parses parameter from the JSON format of Airnode tooling.
+    /// `index` is the position of the parameter, used in the errors
+    pub fn from_json(src: &Value, index: usize) -> Result<Self, JsonError> {
+        let obj: &Map<String, Value> = match src.as_object() {
+            Some(x) => x,
+            None => return Err(JsonError::NotAnObject(index)),
+        };
+        let field = |key: &str| -> Result<&Value, JsonError> {
+            match obj.get(key) {
+                Some(x) => Ok(x),
+                None => Err(JsonError::MissingField {
+                    param: index,
+                    field: key.to_owned(),
+                }),
+            }
+        };
+        let invalid = |reason: &str| JsonError::InvalidValue {
+            param: index,
+            reason: reason.to_owned(),
+        };
+        let kind = match field("type")?.as_str() {
+            Some(x) => x,
+            None => return Err(invalid("type should be a string")),
+        };
+        let name = match field("name")?.as_str() {
+            Some(x) => x.to_owned(),
+            None => return Err(invalid("name should be a string")),
+        };
+        let value = field("value")?;
+        // integers could be provided as JSON numbers as well
+        let text = match value {
+            Value::String(s) => s.clone(),
+            Value::Number(n) if kind == "uint256" || kind == "int256" => n.to_string(),
+            _ => return Err(invalid("value should be a string")),
+        };
+        let from_hex = |expected: Option<usize>| -> Result<Vec<u8>, JsonError> {
+            let digits = match text.strip_prefix("0x") {
+                Some(x) => x,
+                None => return Err(invalid("hex string should start with 0x")),
+            };
+            let bytes = match hex::decode(digits) {
+                Ok(x) => x,
+                Err(e) => return Err(invalid(&e.to_string())),
+            };
+            match expected {
+                Some(sz) if bytes.len() != sz => {
+                    Err(invalid(&format!("{} bytes are expected", sz)))
+                }
+                _ => Ok(bytes),
+            }
+        };
+        match kind {
+            "address" => Ok(Self::Address {
+                name,
+                value: H160::from_slice(&from_hex(Some(20))?),
+            }),
+            "bytes" => Ok(Self::Bytes {
+                name,
+                value: from_hex(None)?,
+            }),
+            "bytes32" => Ok(Self::Bytes32 {
+                name,
+                value: U256::from_big_endian(&from_hex(Some(32))?),
+            }),
+            "int256" => match I256::from_str(&text) {
+                Ok(value) => Ok(Self::Int256 { name, value }),
+                Err(e) => Err(invalid(&e.to_string())),
+            },
+            "string" => Ok(Self::String { name, value: text }),
+            "string32" => {
+                if text.len() > 32 {
+                    return Err(invalid("string should not exceed 32 bytes"));
+                }
+                Ok(Self::String32 { name, value: text })
+            }
+            "uint256" => {
+                if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid(&format!("invalid integer {}", text)));
+                }
+                match U256::from_dec_str(&text) {
+                    Ok(value) => Ok(Self::Uint256 { name, value }),
+                    Err(_) => Err(invalid("integer overflow")),
+                }
+            }
+            _ => Err(JsonError::UnknownType {
+                param: index,
+                kind: kind.to_owned(),
+            }),
+        }
+    }
+}
+
+impl ABI {
+    /// returns list of parameters in the JSON format of Airnode tooling
+    pub fn to_json(&self) -> Value {
+        Value::Array(self.params.iter().map(|p| p.to_json()).collect())
+    }
+
+    /// parses list of parameters in the JSON format of Airnode tooling
+    pub fn from_json(src: &Value) -> Result<Self, JsonError> {
+        let list = match src.as_array() {
+            Some(x) => x,
+            None => return Err(JsonError::NotAnArray),
+        };
+        let mut params = vec![];
+        for (index, item) in list.iter().enumerate() {
+            params.push(Param::from_json(item, index)?);
+        }
+        Ok(Self::new(params))
+    }
+
+    /// parses list of parameters from JSON string in the format of Airnode tooling
+    pub fn from_json_str(src: &str) -> Result<Self, JsonError> {
+        match serde_json::from_str::<Value>(src) {
+            Ok(x) => Self::from_json(&x),
+            Err(e) => Err(JsonError::InvalidJson(e.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hex_literal::hex;
+
+    #[test]
+    fn it_converts_all_types() {
+        let abi = ABI::new(vec![
+            Param::Address {
+                name: "wallet".to_owned(),
+                value: hex!("4128922394C63A204Dd98ea6fbd887780b78bb7d").into(),
+            },
+            Param::Bytes {
+                name: "data".to_owned(),
+                value: hex!("123abc").to_vec(),
+            },
+            Param::Bytes32 {
+                name: "key".to_owned(),
+                value: U256::from(1),
+            },
+            Param::Int256 {
+                name: "delta".to_owned(),
+                value: I256::from(-1000),
+            },
+            Param::String {
+                name: "_path".to_owned(),
+                value: "data.price".to_owned(),
+            },
+            Param::String32 {
+                name: "from".to_owned(),
+                value: "ETH".to_owned(),
+            },
+            Param::Uint256 {
+                name: "_times".to_owned(),
+                value: U256::from_dec_str("1000000000000000000000").unwrap(),
+            },
+        ]);
+        let expected = json!([
+            {"type": "address", "name": "wallet", "value": "0x4128922394c63a204dd98ea6fbd887780b78bb7d"},
+            {"type": "bytes", "name": "data", "value": "0x123abc"},
+            {"type": "bytes32", "name": "key", "value": "0x0000000000000000000000000000000000000000000000000000000000000001"},
+            {"type": "int256", "name": "delta", "value": "-1000"},
+            {"type": "string", "name": "_path", "value": "data.price"},
+            {"type": "string32", "name": "from", "value": "ETH"},
+            {"type": "uint256", "name": "_times", "value": "1000000000000000000000"},
+        ]);
+        assert_eq!(abi.to_json(), expected);
+        assert_eq!(ABI::from_json(&expected).unwrap(), abi);
+    }
+
+    #[test]
+    fn it_converts_extended_types_into_string32() {
+        let abi = ABI::new(vec![
+            Param::Bool {
+                name: "flag".to_owned(),
+                value: true,
+            },
+            Param::Date {
+                name: "start".to_owned(),
+                year: 2021,
+                month: 1,
+                day: 19,
+            },
+        ]);
+        let expected = json!([
+            {"type": "string32", "name": "flag", "value": "true"},
+            {"type": "string32", "name": "start", "value": "2021-01-19"},
+        ]);
+        assert_eq!(abi.to_json(), expected);
+    }
+
+    #[test]
+    fn it_parses_numbers() {
+        let abi = ABI::from_json_str(
+            r#"[{"type":"uint256","name":"a","value":42},{"type":"int256","name":"b","value":-42}]"#,
+        )
+        .unwrap();
+        assert_eq!(abi.params[0].get_value(), "42");
+        assert_eq!(abi.params[1].get_value(), "-42");
+    }
+
+    #[test]
+    fn it_reports_errors() {
+        let cases = vec![
+            ("{}", JsonError::NotAnArray),
+            ("[1]", JsonError::NotAnObject(0)),
+            (
+                r#"[{"type":"string","value":"x"}]"#,
+                JsonError::MissingField {
+                    param: 0,
+                    field: "name".to_owned(),
+                },
+            ),
+            (
+                r#"[{"type":"string","name":"a","value":"x"},{"type":"bool","name":"b","value":"true"}]"#,
+                JsonError::UnknownType {
+                    param: 1,
+                    kind: "bool".to_owned(),
+                },
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(ABI::from_json_str(input), Err(expected));
+        }
+        let invalid = vec![
+            r#"[{"type":"address","name":"a","value":"0x1234"}]"#,
+            r#"[{"type":"bytes","name":"a","value":"123abc"}]"#,
+            r#"[{"type":"bytes32","name":"a","value":"0x01"}]"#,
+            r#"[{"type":"uint256","name":"a","value":"-1"}]"#,
+            r#"[{"type":"uint256","name":"a","value":"1.5"}]"#,
+            r#"[{"type":"int256","name":"a","value":"abc"}]"#,
+            r#"[{"type":"string","name":"a","value":1}]"#,
+            r#"[{"type":"string32","name":"a","value":"123456789012345678901234567890123"}]"#,
+        ];
+        for input in invalid {
+            match ABI::from_json_str(input) {
+                Err(JsonError::InvalidValue {
+                    param: 0,
+                    reason: _,
+                }) => {}
+                x => panic!("unexpected result {:?} for {}", x, input),
+            }
+        }
+        match ABI::from_json_str("[") {
+            Err(JsonError::InvalidJson(_)) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+}
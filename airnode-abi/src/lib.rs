@@ -6,9 +6,35 @@
 //! Parameters from contract event logs are consumed as `Vec<U256>`, which avoids reading
 //! random raw bytes and provides guarantee of a proper data alignment on input.
 //!
+//! Raw `parameters` bytes (or their hex representation from JSON-RPC API) could be decoded
+//! with `ABI::from_bytes` and `ABI::from_hex`, which validate the alignment first.
+//! `ABI::to_bytes` and `ABI::to_hex` produce the bytes expected by AirnodeRrp contract.
+//!
 //! Second parameter of decoding is `strict` flag, which defines whether decoding
 //! could be done into extended types (`String32`,`Bool`,`Date`)
-//! that are actually represented as `Bytes32` on a protocol level.
+//! that are actually represented as `Bytes32` or `String32` on a protocol level.
+//! `ABI::decode_with_options` gives full control over these heuristics with `DecodeOptions`:
+//! built-in interpreters (bool, date, datetime, string32, address, number) could be
+//! enabled, disabled and reordered, and custom `Bytes32Interpreter` could be registered.
+//!
+//! Schema characters follow the current Airnode ABI specification (`a b B s S i u`).
+//! Data encoded by the legacy versions of this library, that used `B` for `String32`,
+//! could be decoded with `ABI::decode_legacy`.
+//!
+//! `AbiBuilder` constructs parameters with typed adders and reports every violation
+//! of the encoding rules in `build()`. Values could be read back with typed getters
+//! (`get_u256`, `get_address`, `get_string`...).
+//!
+//! Parameters could be exchanged with Airnode tooling in its JSON format
+//! (`[{"type":"string32","name":"from","value":"ETH"}]`) using `ABI::to_json` and `ABI::from_json`.
+//!
+//! `ReservedParameters` reproduce the processing of the API response by Airnode:
+//! the value is extracted by `_path`, multiplied by `_times` and encoded as `_type`.
+//! `ReservedParameters::decode_response` turns the fulfillment `data` back into typed values.
+//!
+//! Structures with `serde` traits could be converted into `ABI` with `to_abi` and back with `from_abi`.
+//! Fields become named parameters, their types are chosen from the Rust types,
+//! `U256`, `H160`, `Bytes32`, `String32` and `Date` are marked with the helpers from `attr` module.
 //!
 //! ### decoding example
 //! ```
@@ -42,16 +68,65 @@
 //! }
 //! ```
 //! Please see more examples for each type of the parameter in unit tests.
+//!
+//! The library is `no_std` compatible (with `alloc`), when its default `std` feature is disabled.
+//! `wasm` feature adds JavaScript bindings, see `wasm` module.
+//! `chrono` feature adds conversions of dates, times and timestamps, see `dates` module.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
+#[macro_use]
+extern crate alloc;
+
+pub mod attr;
+mod builder;
+#[cfg(feature = "chrono")]
+pub mod dates;
+mod de;
+mod decimal;
 mod decode;
 mod encode;
+mod i256;
+mod interpret;
+mod json;
+mod partial;
+mod response;
+mod ser;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+/// types, that are not in the prelude of `no_std` crates
+mod prelude {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::boxed::Box;
+    #[cfg(not(feature = "std"))]
+    pub use alloc::collections::{BTreeMap as Map, BTreeSet as Set};
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    pub use std::collections::{HashMap as Map, HashSet as Set};
+}
 
-use decode::{chunk_to_address, chunk_to_int, chunk_to_str, chunk_to_vec, str_to_date};
-use encode::{address_chunk, chunks, date_chunk, int_chunk, str_chunk32, str_chunks};
+pub use builder::{AbiBuilder, BuildError, BuildErrors};
+use core::fmt;
+pub use de::from_abi;
+pub use decimal::{Decimal, DecimalError, Rounding};
+use decode::{bytes_to_chunks, chunk_to_address, chunk_to_int, chunk_to_str, chunk_to_vec};
+use encode::{
+    address_chunk, chunks, chunks_to_bytes, date_chunk, int_chunk, str_chunk32, str_chunks,
+};
 use ethereum_types::{H160, U256};
+pub use i256::{I256Error, I256};
+pub use interpret::{
+    AddressInterpreter, BoolInterpreter, Bytes32Interpreter, Bytes32Value, DateInterpreter,
+    DateTimeInterpreter, DecodeOptions, NumberInterpreter, String32Interpreter,
+};
+pub use json::JsonError;
+pub use partial::{ParamFailure, PartialABI};
+use prelude::*;
+pub use response::{ReservedParameters, ResponseError, ResponseType, ResponseValue};
+pub use ser::{to_abi, SerdeError};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::fmt;
 use thiserror::Error;
 
 #[derive(Debug, Error, Serialize)]
@@ -68,7 +143,7 @@ pub enum EncodingError {
     InvalidDay,
 }
 
-#[derive(Debug, Clone, Error, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
 pub enum DecodingError {
     #[error("no input")]
     NoInput,
@@ -82,6 +157,18 @@ pub enum DecodingError {
     InvalidSchemaCharacter(char),
     #[error("invalid UTF-8 string {0}")]
     InvalidUtf8String(String),
+    #[error("invalid hex string {0}")]
+    InvalidHex(String),
+    #[error("input size {0} is not a multiple of 32 bytes")]
+    InvalidInputSize(usize),
+    #[error("parameter {param}: input is truncated at word {offset}")]
+    UnexpectedEnd { param: usize, offset: usize },
+    #[error("parameter {param}: invalid data offset at word {offset}")]
+    InvalidOffset { param: usize, offset: usize },
+    #[error("parameter {param}: invalid data length at word {offset}")]
+    InvalidLength { param: usize, offset: usize },
+    #[error("parameter {param}: data at word {offset} overlaps with other data")]
+    OverlappingData { param: usize, offset: usize },
 }
 
 /// Atomic parameter in the Airnode ABI
@@ -90,29 +177,27 @@ pub enum DecodingError {
 pub enum Param {
     /// parameter that embeds EVM address (160 bits, H160)
     Address { name: String, value: H160 },
-    /// (non-strict) parameter that embeds boolean value, stored as single Bytes32 value, encoded as bytes of "true" or "false" string
+    /// (non-strict) parameter that embeds boolean value, stored as single String32 value, encoded as bytes of "true" or "false" string
     Bool { name: String, value: bool },
     /// parameter that embeds array of bytes (dynamic size)
     Bytes { name: String, value: Vec<u8> },
     /// parameter that embeds single 256 bits value
     Bytes32 { name: String, value: U256 },
-    /// (non-strict) parameter that embeds date value, stored as single Bytes32 value, encoded as bytes ISO-8601 string
+    /// (non-strict) parameter that embeds date value, stored as single String32 value, encoded as bytes ISO-8601 string
     Date {
         name: String,
         year: i32,
         month: u32,
         day: u32,
     },
-    /// parameter that embeds signed 256 bits value (there is no type of I256 in Ethereum primitives)
-    Int256 {
-        name: String,
-        value: U256,
-        sign: i32, // we need to store the sign separately as we don't have that type
-    },
+    /// (non-strict) parameter that embeds RFC3339 date and time, stored as single String32 value
+    DateTime { name: String, value: String },
+    /// parameter that embeds signed 256 bits value
+    Int256 { name: String, value: I256 },
     /// parameter that embeds UTF-8 string (dynamic size)
     String { name: String, value: String },
-    /// (non-strict) parameter that embeds string as single Bytes32 value. The length of the string should not exceed 32 bytes
-    /// it will be decoded correctly if this is non-empty valid Utf-8 string
+    /// parameter that embeds string as single 32 bytes value. The length of the string should not exceed 32 bytes
+    /// With `String32Interpreter` `Bytes32` values are also decoded into it, if they contain valid non-empty Utf-8 string
     String32 { name: String, value: String },
     /// parameter that embeds unsigned 256 bits value
     Uint256 { name: String, value: U256 },
@@ -132,11 +217,8 @@ impl Param {
                 month: _,
                 day: _,
             } => name,
-            Self::Int256 {
-                name,
-                value: _,
-                sign: _,
-            } => name,
+            Self::DateTime { name, value: _ } => name,
+            Self::Int256 { name, value: _ } => name,
             Self::String { name, value: _ } => name,
             Self::String32 { name, value: _ } => name,
             Self::Uint256 { name, value: _ } => name,
@@ -149,38 +231,29 @@ impl Param {
             Self::Address { name: _, value } => format!("{:?}", value),
             Self::Bool { name: _, value } => format!("{}", value),
             Self::Bytes { name: _, value } => format!("{:x?}", value),
-            Self::Bytes32 { name: _, value } => format!("{:x?}", value),
+            Self::Bytes32 { name: _, value } => format!("0x{:064x}", value),
             Self::Date {
                 name: _,
                 year,
                 month,
                 day,
             } => format!("{:04}-{:02}-{:02}", year, month, day),
-            Self::Int256 {
-                name: _,
-                value,
-                sign,
-            } => {
-                if *sign >= 0 {
-                    format!("{:x?}", value)
-                } else {
-                    format!("-{:x?}", value)
-                }
-            }
+            Self::DateTime { name: _, value } => value.clone(),
+            Self::Int256 { name: _, value } => format!("{}", value),
             Self::String { name: _, value } => value.clone(),
             Self::String32 { name: _, value } => value.clone(),
-            Self::Uint256 { name: _, value } => format!("{:x?}", value),
+            Self::Uint256 { name: _, value } => value.to_string(),
         }
     }
 
     /// returns character of the parameter for encoding
     /// - Upper case letters refer to dynamically sized types
     /// - Lower case letters refer to statically sized types
-    /// - String32 is encoded into Bytes32
+    /// - Bool, Date and DateTime are encoded as String32
     pub fn get_char(&self) -> char {
         match &self {
             Self::Address { name: _, value: _ } => 'a',
-            Self::Bool { name: _, value: _ } => 'b',
+            Self::Bool { name: _, value: _ } => 's',
             Self::Bytes { name: _, value: _ } => 'B',
             Self::Bytes32 { name: _, value: _ } => 'b',
             Self::Date {
@@ -188,14 +261,11 @@ impl Param {
                 year: _,
                 month: _,
                 day: _,
-            } => 'b',
-            Self::Int256 {
-                name: _,
-                value: _,
-                sign: _,
-            } => 'i',
+            } => 's',
+            Self::DateTime { name: _, value: _ } => 's',
+            Self::Int256 { name: _, value: _ } => 'i',
             Self::String { name: _, value: _ } => 'S',
-            Self::String32 { name: _, value: _ } => 'B',
+            Self::String32 { name: _, value: _ } => 's',
             Self::Uint256 { name: _, value: _ } => 'u',
         }
     }
@@ -233,9 +303,8 @@ impl Param {
                 month,
                 day,
             } => Ok(vec![str_chunk32(name)?, date_chunk(*year, *month, *day)?]),
-            Self::Int256 { name, value, sign } => {
-                Ok(vec![str_chunk32(name)?, int_chunk(*value, *sign)])
-            }
+            Self::DateTime { name, value } => Ok(vec![str_chunk32(name)?, str_chunk32(value)?]),
+            Self::Int256 { name, value } => Ok(vec![str_chunk32(name)?, int_chunk(*value)]),
             Self::String { name, value: _ } => {
                 // dynamic structure, second parameter is reserved to be overwritten later
                 // it will contain the offset of the data
@@ -274,16 +343,81 @@ impl fmt::Display for Param {
     }
 }
 
+/// Error of reading the parameter with the typed getter of `ABI`
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum ParamError {
+    #[error("parameter {0} is missing")]
+    Missing(String),
+    #[error("parameter {name}: {expected} is expected, got {actual}")]
+    TypeMismatch {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+}
+
 /// Airnode ABI object that can be encoded into the vector of U256 and decoded from it
-#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(PartialEq, Clone, Serialize, Deserialize)]
+#[serde(from = "RawABI")]
 pub struct ABI {
     /// Id of the ABI version. It is always "1" so far
     #[serde(skip_serializing)]
     pub version: u8,
     /// Schema string. Each parameter is represented by a char
     pub schema: String,
-    /// List of ABI parameters.
-    pub params: Vec<Param>,
+    /// List of ABI parameters. It is private to keep `index` in sync with it
+    params: Vec<Param>,
+    /// positions of the parameters by their names (the first one for duplicates)
+    #[serde(skip)]
+    index: Map<String, usize>,
+}
+
+/// serialized Airnode ABI, that is deserialized without the index of parameters
+#[derive(Deserialize)]
+struct RawABI {
+    version: u8,
+    schema: String,
+    params: Vec<Param>,
+}
+
+impl From<RawABI> for ABI {
+    fn from(raw: RawABI) -> Self {
+        Self {
+            version: raw.version,
+            schema: raw.schema,
+            index: index_params(&raw.params),
+            params: raw.params,
+        }
+    }
+}
+
+impl fmt::Debug for ABI {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ABI")
+            .field("version", &self.version)
+            .field("schema", &self.schema)
+            .field("params", &self.params)
+            .finish()
+    }
+}
+
+/// returns positions of the parameters by their names
+fn index_params(params: &[Param]) -> Map<String, usize> {
+    let mut index = Map::new();
+    for (i, p) in params.iter().enumerate() {
+        index.entry(p.get_name().to_owned()).or_insert(i);
+    }
+    index
+}
+
+/// returns name of the type of the parameter for the errors
+fn type_name(p: &Param) -> &'static str {
+    match p {
+        Param::Bool { .. } => "bool",
+        Param::Date { .. } => "date",
+        Param::DateTime { .. } => "datetime",
+        _ => p.get_type(),
+    }
 }
 
 /// get parameters encoded into schema string.
@@ -300,6 +434,7 @@ impl ABI {
         Self {
             version: 0x31,
             schema: encode_schema(0x31, &params),
+            index: index_params(&params),
             params,
         }
     }
@@ -310,31 +445,118 @@ impl ABI {
             version: 0x31,
             schema: "1".to_owned(),
             params: vec![],
+            index: Map::new(),
         }
     }
 
     /// constructor of Airnode ABI with a single parameter
     pub fn only(param: Param) -> Self {
-        let params = vec![param];
-        Self {
-            version: 0x31,
-            schema: encode_schema(0x31, &params),
-            params: params,
-        }
+        Self::new(vec![param])
     }
 
     /// get parameter by its name
     pub fn get(&self, key: &str) -> Option<Param> {
-        let filtered: Vec<&Param> = self
-            .params
-            .iter()
-            .filter(|&x| x.get_name() == key)
-            .collect();
-        if filtered.len() > 0 {
-            Some(filtered[0].clone())
-        } else {
-            None
-        }
+        self.find(key).cloned()
+    }
+
+    /// list of the parameters
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+
+    /// takes the list of the parameters
+    pub fn into_params(self) -> Vec<Param> {
+        self.params
+    }
+
+    /// returns reference to the parameter by its name (the first one for duplicates)
+    pub fn find(&self, key: &str) -> Option<&Param> {
+        self.index.get(key).map(|i| &self.params[*i])
+    }
+
+    fn typed<'a, T>(
+        &'a self,
+        key: &str,
+        expected: &str,
+        f: impl Fn(&'a Param) -> Option<T>,
+    ) -> Result<T, ParamError> {
+        let p = match self.find(key) {
+            Some(x) => x,
+            None => return Err(ParamError::Missing(key.to_owned())),
+        };
+        f(p).ok_or_else(|| ParamError::TypeMismatch {
+            name: key.to_owned(),
+            expected: expected.to_owned(),
+            actual: type_name(p).to_owned(),
+        })
+    }
+
+    /// returns value of `Address` parameter
+    pub fn get_address(&self, key: &str) -> Result<H160, ParamError> {
+        self.typed(key, "address", |p| match p {
+            Param::Address { value, .. } => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// returns value of `Bool` parameter
+    pub fn get_bool(&self, key: &str) -> Result<bool, ParamError> {
+        self.typed(key, "bool", |p| match p {
+            Param::Bool { value, .. } => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// returns value of `Bytes` parameter
+    pub fn get_bytes(&self, key: &str) -> Result<&[u8], ParamError> {
+        self.typed(key, "bytes", |p| match p {
+            Param::Bytes { value, .. } => Some(value.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// returns value of `Bytes32` parameter
+    pub fn get_bytes32(&self, key: &str) -> Result<U256, ParamError> {
+        self.typed(key, "bytes32", |p| match p {
+            Param::Bytes32 { value, .. } => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// returns year, month and day of `Date` parameter
+    pub fn get_date(&self, key: &str) -> Result<(i32, u32, u32), ParamError> {
+        self.typed(key, "date", |p| match p {
+            Param::Date {
+                year, month, day, ..
+            } => Some((*year, *month, *day)),
+            _ => None,
+        })
+    }
+
+    /// returns value of `Int256` parameter
+    pub fn get_i256(&self, key: &str) -> Result<I256, ParamError> {
+        self.typed(key, "int256", |p| match p {
+            Param::Int256 { value, .. } => Some(*value),
+            _ => None,
+        })
+    }
+
+    /// returns value of `String`, `String32` or `DateTime` parameter
+    pub fn get_string(&self, key: &str) -> Result<&str, ParamError> {
+        self.typed(key, "string", |p| match p {
+            Param::String { value, .. } => Some(value.as_str()),
+            Param::String32 { value, .. } => Some(value.as_str()),
+            Param::DateTime { value, .. } => Some(value.as_str()),
+            _ => None,
+        })
+    }
+
+    /// returns value of `Uint256` parameter
+    pub fn get_u256(&self, key: &str) -> Result<U256, ParamError> {
+        self.typed(key, "uint256", |p| match p {
+            Param::Uint256 { value, .. } => Some(*value),
+            _ => None,
+        })
     }
 
     /// encodes ABI into vector or 256 bit values
@@ -344,7 +566,7 @@ impl ABI {
             return Err(EncodingError::TooManyParams);
         }
         let mut out = vec![str_chunk32(encode_schema(0x31, &self.params).as_str())?];
-        let mut m: HashMap<usize, usize> = HashMap::new();
+        let mut m: Map<usize, usize> = Map::new();
         // first loop - pushing chunks of the fixed size
         for (i, p) in self.params.iter().enumerate() {
             if !p.is_fixed_size() {
@@ -369,6 +591,17 @@ impl ABI {
         Ok(out)
     }
 
+    /// encodes ABI into bytes, exactly as they are passed as `parameters`
+    /// to the calls of AirnodeRrp contract
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodingError> {
+        Ok(chunks_to_bytes(&self.encode()?))
+    }
+
+    /// encodes ABI into hex string of bytes, prefixed with `0x`
+    pub fn to_hex(&self) -> Result<String, EncodingError> {
+        Ok(format!("0x{}", hex::encode(self.to_bytes()?)))
+    }
+
     /// decodes ABI from the vector or 256 bit values.
     /// This function can be used when data doesn't contain schema, but you know it from the other source.
     pub fn decode_with_schema(
@@ -387,30 +620,43 @@ impl ABI {
         Self::decode(&input, strict)
     }
 
-    /// decodes ABI from the vector or 256 bit values
+    /// decodes ABI from the vector or 256 bit values.
+    /// Input is never trusted: truncated data, invalid offsets and lengths
+    /// of dynamic values are reported as errors instead of panics.
+    /// Shortcut for `decode_with_options` with `DecodeOptions::strict()` or `DecodeOptions::default()`
     pub fn decode(input: &Vec<U256>, strict: bool) -> Result<Self, DecodingError> {
-        if input.len() < 1 {
-            return Err(DecodingError::NoInput);
-        }
-        let schema_chunk = input.get(0).unwrap();
-        if schema_chunk.is_zero() {
-            return Err(DecodingError::NoSchema);
+        Self::decode_with_options(input, &Self::options(strict))
+    }
+
+    /// decodes ABI from the vector or 256 bit values, in compatibility mode
+    /// with the legacy versions of this library, which used `B` schema character for `String32`.
+    /// `B` parameters with the value that cannot be an offset of the dynamic data are decoded as `String32`.
+    pub fn decode_legacy(input: &[U256], strict: bool) -> Result<Self, DecodingError> {
+        Self::decode_with_options(input, &Self::options(strict).legacy(true))
+    }
+
+    fn options(strict: bool) -> DecodeOptions {
+        if strict {
+            DecodeOptions::strict()
+        } else {
+            DecodeOptions::default()
         }
+    }
 
-        let schema: String = match chunk_to_str(*schema_chunk) {
-            Ok(x) => x,
-            Err(e) => return Err(DecodingError::InvalidUtf8String(e.to_string())),
-        };
+    /// decodes ABI from the vector or 256 bit values.
+    /// `Bytes32` and `String32` values are interpreted with the interpreters from `options`
+    pub fn decode_with_options(
+        input: &[U256],
+        options: &DecodeOptions,
+    ) -> Result<Self, DecodingError> {
+        let schema = Self::decode_schema(input)?;
         let mut params: Vec<Param> = vec![];
         if schema.len() > 1 {
-            let ch_version = schema.chars().nth(0).unwrap();
-            if ch_version != '1' {
-                return Err(DecodingError::InvalidVersion);
-            }
-            let mut offs: usize = 1;
+            let num_params = schema.chars().count() - 1;
+            let mut cursor = Cursor::new(input, 1 + 2 * num_params);
             let mut errors: Vec<DecodingError> = vec![];
-            schema.chars().skip(1).for_each(|ch| {
-                match Self::from_chunks(ch, &input, &mut offs, strict) {
+            schema.chars().skip(1).enumerate().for_each(|(index, ch)| {
+                match Self::from_chunks(ch, &mut cursor, index, options) {
                     Ok(p) => params.push(p),
                     Err(e) => errors.push(e),
                 }
@@ -422,61 +668,98 @@ impl ABI {
         Ok(Self::new(params))
     }
 
-    /// decodes name and value from array of chunks, starting at the given `offset`
+    /// reads schema string from the first word of the input and checks its version
+    fn decode_schema(input: &[U256]) -> Result<String, DecodingError> {
+        let schema_chunk = match input.first() {
+            Some(x) => x,
+            None => return Err(DecodingError::NoInput),
+        };
+        if schema_chunk.is_zero() {
+            return Err(DecodingError::NoSchema);
+        }
+        let schema: String = match chunk_to_str(*schema_chunk) {
+            Ok(x) => x,
+            Err(e) => return Err(DecodingError::InvalidUtf8String(e.to_string())),
+        };
+        if schema.len() > 1 && !schema.starts_with('1') {
+            return Err(DecodingError::InvalidVersion);
+        }
+        Ok(schema)
+    }
+
+    /// decodes ABI from raw bytes, i.e. `parameters` field of the transaction or event.
+    /// The size of the input must be a multiple of 32 bytes.
+    pub fn from_bytes(input: &[u8], strict: bool) -> Result<Self, DecodingError> {
+        Self::from_bytes_with_options(input, &Self::options(strict))
+    }
+
+    /// decodes ABI from raw bytes, using the interpreters of `options`
+    pub fn from_bytes_with_options(
+        input: &[u8],
+        options: &DecodeOptions,
+    ) -> Result<Self, DecodingError> {
+        if !input.len().is_multiple_of(32) {
+            return Err(DecodingError::InvalidInputSize(input.len()));
+        }
+        Self::decode_with_options(&bytes_to_chunks(input), options)
+    }
+
+    /// decodes ABI from hex string of bytes (with or without `0x` prefix),
+    /// as it is returned by JSON-RPC API
+    pub fn from_hex(input: &str, strict: bool) -> Result<Self, DecodingError> {
+        let digits = input.strip_prefix("0x").unwrap_or(input);
+        let bytes = match hex::decode(digits) {
+            Ok(x) => x,
+            Err(e) => return Err(DecodingError::InvalidHex(e.to_string())),
+        };
+        Self::from_bytes(&bytes, strict)
+    }
+
+    /// decodes name and value from array of chunks, starting at the current offset of the `cursor`
     /// and using type from `ch` character.
-    /// Returns `Param` instance and updates `offset` with the bigger value.
+    /// Returns `Param` instance and moves the cursor to the next parameter.
     fn from_chunks(
         ch: char,
-        arr: &Vec<U256>,
-        offset: &mut usize,
-        strict: bool,
+        cursor: &mut Cursor,
+        index: usize,
+        options: &DecodeOptions,
     ) -> Result<Param, DecodingError> {
-        let name: String = match chunk_to_str(arr[*offset]) {
+        // the head of the parameter is at the fixed position, even if the previous one has failed
+        cursor.seek(index);
+        let name: String = match chunk_to_str(cursor.next(index)?) {
             Ok(x) => x,
             Err(e) => return Err(DecodingError::InvalidUtf8String(e.to_string())),
         };
-        *offset += 1;
         if ch == 'b' {
-            let value = arr[*offset];
-            *offset += 1;
-            if !strict {
-                // if we are not in the strict mode, we will be trying to parse to a string or to some known type
-                if let Ok(v) = chunk_to_str(value) {
-                    if v == "true" {
-                        return Ok(Param::Bool { name, value: true });
-                    } else if v == "false" {
-                        return Ok(Param::Bool { name, value: false });
-                    }
-                    if let Some((year, month, day)) = str_to_date(&v) {
-                        return Ok(Param::Date {
-                            name,
-                            year,
-                            month,
-                            day,
-                        });
-                    }
-                    return Ok(Param::String32 { name, value: v });
-                }
+            let value = Bytes32Value::new(cursor.next(index)?, ch);
+            if let Some(p) = options.interpret(&name, &value) {
+                return Ok(p);
+            }
+            return Ok(Param::Bytes32 {
+                name,
+                value: value.value,
+            });
+        } else if ch == 's' || (ch == 'B' && options.is_legacy() && !cursor.has_offset()) {
+            let chunk = cursor.next(index)?;
+            let value = match chunk_to_str(chunk) {
+                Ok(x) => x,
+                Err(e) => return Err(DecodingError::InvalidUtf8String(e.to_string())),
+            };
+            if let Some(p) = options.interpret(&name, &Bytes32Value::new(chunk, 's')) {
+                return Ok(p);
             }
-            return Ok(Param::Bytes32 { name, value });
+            return Ok(Param::String32 { name, value });
         } else if ch == 'u' {
-            let value = arr[*offset];
-            *offset += 1;
+            let value = cursor.next(index)?;
             return Ok(Param::Uint256 { name, value });
         } else if ch == 'a' {
-            let value = chunk_to_address(arr[*offset]);
-            *offset += 1;
+            let value = chunk_to_address(cursor.next(index)?);
             return Ok(Param::Address { name, value });
         } else if ch == 'i' {
-            let (value, sign) = chunk_to_int(arr[*offset]);
-            *offset += 1;
-            return Ok(Param::Int256 { name, value, sign });
+            let value = chunk_to_int(cursor.next(index)?);
+            return Ok(Param::Int256 { name, value });
         } else if ch == 'B' || ch == 'S' {
-            let value_index: usize = arr[*offset].as_usize(); // todo: handle failure
-            *offset += 1;
-            let data_offset = value_index / 32;
-            let value_size: usize = arr[data_offset].as_usize(); // todo: handle failure
-            let value = chunk_to_vec(arr, data_offset + 1, value_size);
+            let value = cursor.dynamic(index)?;
             if ch == 'B' {
                 return Ok(Param::Bytes { name, value });
             }
@@ -490,6 +773,118 @@ impl ABI {
     }
 }
 
+/// Reading position in the untrusted input.
+/// Every access is bounds-checked and the ranges of the dynamic data
+/// are tracked to detect values that overlap each other.
+struct Cursor<'a> {
+    arr: &'a [U256],
+    /// index of the next word of the head
+    offset: usize,
+    /// index of the first word after the head (schema, names and values)
+    heads_end: usize,
+    /// ranges of words occupied by dynamic values
+    tails: Vec<(usize, usize)>,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(arr: &'a [U256], heads_end: usize) -> Self {
+        Self {
+            arr,
+            offset: 1,
+            heads_end,
+            tails: vec![],
+        }
+    }
+
+    /// returns the next word of the head for the parameter `param`
+    fn next(&mut self, param: usize) -> Result<U256, DecodingError> {
+        match self.arr.get(self.offset) {
+            Some(word) => {
+                self.offset += 1;
+                Ok(*word)
+            }
+            None => Err(DecodingError::UnexpectedEnd {
+                param,
+                offset: self.offset,
+            }),
+        }
+    }
+
+    /// moves to the head of the parameter `param`
+    fn seek(&mut self, param: usize) {
+        self.offset = 1 + 2 * param;
+    }
+
+    /// returns index of the first word after the head and the dynamic data that were read
+    fn end(&self) -> usize {
+        self.tails
+            .iter()
+            .map(|(_, finish)| *finish)
+            .fold(self.heads_end, usize::max)
+    }
+
+    /// returns whether the next word of the head could be an offset of the dynamic data
+    fn has_offset(&self) -> bool {
+        match self.arr.get(self.offset) {
+            Some(word) => {
+                word.bits() <= 64
+                    && word.low_u64() % 32 == 0
+                    && word.low_u64() / 32 >= self.heads_end as u64
+                    && word.low_u64() / 32 < self.arr.len() as u64
+            }
+            None => false,
+        }
+    }
+
+    /// reads bytes of the dynamic value for the parameter `param`
+    /// The next word of the head contains offset (in bytes) of the data,
+    /// where the first word is its size and the rest is the value padded to 32 bytes.
+    fn dynamic(&mut self, param: usize) -> Result<Vec<u8>, DecodingError> {
+        let head_offset = self.offset;
+        let value_index = self.next(param)?;
+        let invalid_offset = DecodingError::InvalidOffset {
+            param,
+            offset: head_offset,
+        };
+        if value_index.bits() > 64 || value_index.low_u64() % 32 != 0 {
+            return Err(invalid_offset);
+        }
+        let data_offset = value_index.low_u64() / 32;
+        if data_offset >= self.arr.len() as u64 {
+            return Err(invalid_offset);
+        }
+        let data_offset = data_offset as usize;
+        if data_offset < self.heads_end {
+            return Err(DecodingError::OverlappingData {
+                param,
+                offset: data_offset,
+            });
+        }
+        let value_size = self.arr[data_offset];
+        let available = (self.arr.len() - data_offset - 1) * 32;
+        if value_size.bits() > 64 || value_size.low_u64() > available as u64 {
+            return Err(DecodingError::InvalidLength {
+                param,
+                offset: data_offset,
+            });
+        }
+        let value_size = value_size.low_u64() as usize;
+        let end = data_offset + 1 + value_size.div_ceil(32);
+        if self
+            .tails
+            .iter()
+            .any(|(start, finish)| data_offset < *finish && *start < end)
+        {
+            return Err(DecodingError::OverlappingData {
+                param,
+                offset: data_offset,
+            });
+        }
+        self.tails.push((data_offset, end));
+        Ok(chunk_to_vec(self.arr, data_offset + 1, value_size))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -577,9 +972,9 @@ mod tests {
         let mut r = rand_vec(32);
         r[0] &= 0b0111_1111; // Unset the first bit to get positive
         let input = U256::from(into32(&r));
-        let param = Param::Uint256 {
+        let param = Param::Int256 {
             name: rand_str(),
-            value: input,
+            value: I256::from_raw(input),
         };
         let value = ABI::only(param);
         let decoded = ABI::decode(&value.encode().unwrap(), true).unwrap();
@@ -591,15 +986,27 @@ mod tests {
         let mut r = rand_vec(32);
         r[0] |= 0b1000_0000; // Set the first bit to get negative
         let input = U256::from(into32(&r));
-        let param = Param::Uint256 {
+        let param = Param::Int256 {
             name: rand_str(),
-            value: input,
+            value: I256::from_raw(input),
         };
         let value = ABI::only(param);
         let decoded = ABI::decode(&value.encode().unwrap(), true).unwrap();
         assert_eq!(decoded, value);
     }
 
+    #[test]
+    fn it_encodes_decodes_int256_edges() {
+        for v in [I256::MIN, I256::MINUS_ONE, I256::ZERO, I256::MAX] {
+            let value = ABI::only(Param::Int256 {
+                name: rand_str(),
+                value: v,
+            });
+            let decoded = ABI::decode(&value.encode().unwrap(), true).unwrap();
+            assert_eq!(decoded, value);
+        }
+    }
+
     #[test]
     fn it_encodes_empty() {
         let value = ABI::none().encode().unwrap();
@@ -641,8 +1048,7 @@ mod tests {
         let res = ABI::decode(&data, true).unwrap();
         let expected = ABI::only(Param::Int256 {
             name: "TestIntName".to_owned(),
-            value: U256::from(1000),
-            sign: -1,
+            value: I256::from(-1000),
         });
         assert_eq!(res, expected);
     }
@@ -834,8 +1240,7 @@ mod tests {
             },
             Param::Int256 {
                 name: "balance".to_owned(),
-                value: U256::from_dec_str("10000000000000000000").unwrap(),
-                sign: -1,
+                value: "-10000000000000000000".parse().unwrap(),
             },
             Param::Bytes {
                 name: "bytes name".to_owned(),
@@ -849,6 +1254,490 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    /// conformance vectors: every schema character, empty and multi-word dynamic values.
+    /// Expected outputs are the standard Solidity ABI encoding of the `bytes32` schema,
+    /// followed by `bytes32` name and typed value pairs, which is what the reference
+    /// `@api3/airnode-abi` encoder produces. They were computed independently of this crate.
+    fn conformance_vectors() -> Vec<(ABI, String)> {
+        vec![
+            (
+                ABI::none(),
+                ["3100000000000000000000000000000000000000000000000000000000000000"].concat(),
+            ),
+            (
+                ABI::only(Param::Bytes32 {
+                    name: "from".to_owned(),
+                    value: hex!("4554480000000000000000000000000000000000000000000000000000000000")
+                        .into(),
+                }),
+                [
+                    "3162000000000000000000000000000000000000000000000000000000000000",
+                    "66726f6d00000000000000000000000000000000000000000000000000000000",
+                    "4554480000000000000000000000000000000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::String32 {
+                    name: "to".to_owned(),
+                    value: "USD".to_owned(),
+                }),
+                [
+                    "3173000000000000000000000000000000000000000000000000000000000000",
+                    "746f000000000000000000000000000000000000000000000000000000000000",
+                    "5553440000000000000000000000000000000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::Address {
+                    name: "wallet".to_owned(),
+                    value: hex!("4128922394C63A204Dd98ea6fbd887780b78bb7d").into(),
+                }),
+                [
+                    "3161000000000000000000000000000000000000000000000000000000000000",
+                    "77616c6c65740000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000004128922394c63a204dd98ea6fbd887780b78bb7d",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::Uint256 {
+                    name: "_times".to_owned(),
+                    value: U256::from(1000000),
+                }),
+                [
+                    "3175000000000000000000000000000000000000000000000000000000000000",
+                    "5f74696d65730000000000000000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000f4240",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::Int256 {
+                    name: "delta".to_owned(),
+                    value: I256::MINUS_ONE,
+                }),
+                [
+                    "3169000000000000000000000000000000000000000000000000000000000000",
+                    "64656c7461000000000000000000000000000000000000000000000000000000",
+                    "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::String {
+                    name: "_path".to_owned(),
+                    value: "data.result.price".to_owned(),
+                }),
+                [
+                    "3153000000000000000000000000000000000000000000000000000000000000",
+                    "5f70617468000000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000060",
+                    "0000000000000000000000000000000000000000000000000000000000000011",
+                    "646174612e726573756c742e7072696365000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::only(Param::Bytes {
+                    name: "payload".to_owned(),
+                    value: hex!("123abc").to_vec(),
+                }),
+                [
+                    "3142000000000000000000000000000000000000000000000000000000000000",
+                    "7061796c6f616400000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000060",
+                    "0000000000000000000000000000000000000000000000000000000000000003",
+                    "123abc0000000000000000000000000000000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::new(vec![
+                    Param::String {
+                        name: "empty string".to_owned(),
+                        value: "".to_owned(),
+                    },
+                    Param::Bytes {
+                        name: "empty bytes".to_owned(),
+                        value: vec![],
+                    },
+                ]),
+                [
+                    "3153420000000000000000000000000000000000000000000000000000000000",
+                    "656d70747920737472696e670000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000000a0",
+                    "656d707479206279746573000000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000000c0",
+                    "0000000000000000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::new(vec![
+                    Param::String {
+                        name: "message".to_owned(),
+                        value: "你好世界 hello".to_owned(),
+                    },
+                    Param::String32 {
+                        name: "greeting".to_owned(),
+                        value: "你好".to_owned(),
+                    },
+                ]),
+                [
+                    "3153730000000000000000000000000000000000000000000000000000000000",
+                    "6d65737361676500000000000000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000000a0",
+                    "6772656574696e67000000000000000000000000000000000000000000000000",
+                    "e4bda0e5a5bd0000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000012",
+                    "e4bda0e5a5bde4b896e7958c2068656c6c6f0000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+            (
+                ABI::new(vec![
+                    Param::Bytes32 {
+                        name: "from".to_owned(),
+                        value: hex!(
+                            "4554480000000000000000000000000000000000000000000000000000000000"
+                        )
+                        .into(),
+                    },
+                    Param::String32 {
+                        name: "to".to_owned(),
+                        value: "USD".to_owned(),
+                    },
+                    Param::String {
+                        name: "_path".to_owned(),
+                        value: "result".to_owned(),
+                    },
+                    Param::Uint256 {
+                        name: "_times".to_owned(),
+                        value: U256::from(100000),
+                    },
+                    Param::Bytes {
+                        name: "data".to_owned(),
+                        value: (0u8..=0x20).collect(),
+                    },
+                    Param::Int256 {
+                        name: "delta".to_owned(),
+                        value: I256::from(-123456789),
+                    },
+                    Param::Address {
+                        name: "wallet".to_owned(),
+                        value: hex!("4128922394C63A204Dd98ea6fbd887780b78bb7d").into(),
+                    },
+                ]),
+                [
+                    "3162735375426961000000000000000000000000000000000000000000000000",
+                    "66726f6d00000000000000000000000000000000000000000000000000000000",
+                    "4554480000000000000000000000000000000000000000000000000000000000",
+                    "746f000000000000000000000000000000000000000000000000000000000000",
+                    "5553440000000000000000000000000000000000000000000000000000000000",
+                    "5f70617468000000000000000000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000001e0",
+                    "5f74696d65730000000000000000000000000000000000000000000000000000",
+                    "00000000000000000000000000000000000000000000000000000000000186a0",
+                    "6461746100000000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000220",
+                    "64656c7461000000000000000000000000000000000000000000000000000000",
+                    "fffffffffffffffffffffffffffffffffffffffffffffffffffffffff8a432eb",
+                    "77616c6c65740000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000004128922394c63a204dd98ea6fbd887780b78bb7d",
+                    "0000000000000000000000000000000000000000000000000000000000000006",
+                    "726573756c740000000000000000000000000000000000000000000000000000",
+                    "0000000000000000000000000000000000000000000000000000000000000021",
+                    "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f",
+                    "2000000000000000000000000000000000000000000000000000000000000000",
+                ]
+                .concat(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn it_encodes_conformance_vectors() {
+        for (abi, expected) in conformance_vectors() {
+            assert_eq!(
+                abi.to_hex().unwrap(),
+                format!("0x{}", expected),
+                "{:?}",
+                abi
+            );
+        }
+    }
+
+    #[test]
+    fn it_decodes_conformance_vectors() {
+        for (expected, input) in conformance_vectors() {
+            assert_eq!(ABI::from_hex(&input, true).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn it_encodes_decodes_string32() {
+        let param = Param::String32 {
+            name: rand_str(),
+            value: rand_str(),
+        };
+        let value = ABI::only(param);
+        assert_eq!(value.schema, "1s");
+        let decoded = ABI::decode(&value.encode().unwrap(), true).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn it_decodes_string32_strict() {
+        let data: Vec<U256> = vec![
+            hex!("3173000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("54657374426F6F6C000000000000000000000000000000000000000000000000").into(),
+            hex!("7472756500000000000000000000000000000000000000000000000000000000").into(),
+        ];
+        let res = ABI::decode(&data, true).unwrap();
+        let expected = ABI::only(Param::String32 {
+            name: "TestBool".to_owned(),
+            value: "true".to_owned(),
+        });
+        assert_eq!(res, expected);
+        let res = ABI::decode(&data, false).unwrap();
+        let expected = ABI::only(Param::Bool {
+            name: "TestBool".to_owned(),
+            value: true,
+        });
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn it_decodes_legacy_string32() {
+        // legacy versions used 'B' for String32, followed by "Bytes" parameter
+        let data: Vec<U256> = vec![
+            hex!("3142420000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("6e616d6500000000000000000000000000000000000000000000000000000000").into(),
+            hex!("76616c7565000000000000000000000000000000000000000000000000000000").into(),
+            hex!("6279746573000000000000000000000000000000000000000000000000000000").into(),
+            hex!("00000000000000000000000000000000000000000000000000000000000000a0").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000003").into(),
+            hex!("123abc0000000000000000000000000000000000000000000000000000000000").into(),
+        ];
+        match ABI::decode(&data, true) {
+            Err(DecodingError::InvalidOffset {
+                param: 0,
+                offset: 2,
+            }) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+        let res = ABI::decode_legacy(&data, true).unwrap();
+        let expected = ABI::new(vec![
+            Param::String32 {
+                name: "name".to_owned(),
+                value: "value".to_owned(),
+            },
+            Param::Bytes {
+                name: "bytes".to_owned(),
+                value: hex!("123abc").into(),
+            },
+        ]);
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn it_encodes_decodes_empty_bytes() {
+        let param = Param::Bytes {
+            name: rand_str(),
+            value: vec![],
+        };
+        let value = ABI::only(param);
+        let decoded = ABI::decode(&value.encode().unwrap(), true).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn it_shouldnt_decode_truncated() {
+        let data: Vec<U256> = vec![
+            hex!("3161750000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("54657374416464726573734e616d650000000000000000000000000000000000").into(),
+            hex!("0000000000000000000000004128922394c63a204dd98ea6fbd887780b78bb7d").into(),
+            hex!("5465737455496e744e616d650000000000000000000000000000000000000000").into(),
+        ];
+        match ABI::decode(&data, true) {
+            Err(DecodingError::UnexpectedEnd { param, offset }) => {
+                assert_eq!(param, 1);
+                assert_eq!(offset, 4);
+            }
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_shouldnt_decode_invalid_offset() {
+        let offsets: Vec<U256> = vec![
+            U256::from(0x61),                       // not aligned
+            U256::from(0x80),                       // beyond the input
+            U256::MAX,                              // does not fit into usize
+            U256::from(0x60) + (U256::one() << 64), // truncated to a valid offset
+        ];
+        for offs in offsets {
+            let data: Vec<U256> = vec![
+                hex!("3142000000000000000000000000000000000000000000000000000000000000").into(),
+                hex!("5465737442797465734e616d6500000000000000000000000000000000000000").into(),
+                offs,
+                hex!("0000000000000000000000000000000000000000000000000000000000000003").into(),
+            ];
+            match ABI::decode(&data, true) {
+                Err(DecodingError::InvalidOffset { param, offset }) => {
+                    assert_eq!(param, 0);
+                    assert_eq!(offset, 2);
+                }
+                x => panic!("unexpected result {:?} for offset {:?}", x, offs),
+            }
+        }
+    }
+
+    #[test]
+    fn it_shouldnt_decode_overlong_length() {
+        let data: Vec<U256> = vec![
+            hex!("3153000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("54657374537472696e674e616d65000000000000000000000000000000000000").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000060").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000021").into(),
+            hex!("536f6d6520737472696e672076616c7565000000000000000000000000000000").into(),
+        ];
+        match ABI::decode(&data, true) {
+            Err(DecodingError::InvalidLength { param, offset }) => {
+                assert_eq!(param, 0);
+                assert_eq!(offset, 3);
+            }
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_shouldnt_decode_overlapping_data() {
+        // both parameters are pointing to the same data
+        let data: Vec<U256> = vec![
+            hex!("3142420000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("6100000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("00000000000000000000000000000000000000000000000000000000000000a0").into(),
+            hex!("6200000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("00000000000000000000000000000000000000000000000000000000000000a0").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000003").into(),
+            hex!("123abc0000000000000000000000000000000000000000000000000000000000").into(),
+        ];
+        match ABI::decode(&data, true) {
+            Err(DecodingError::OverlappingData { param, offset }) => {
+                assert_eq!(param, 1);
+                assert_eq!(offset, 5);
+            }
+            x => panic!("unexpected result {:?}", x),
+        }
+        // data is pointing into the names and values of the parameters
+        let data: Vec<U256> = vec![
+            hex!("3142000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000000").into(),
+            hex!("0000000000000000000000000000000000000000000000000000000000000020").into(),
+        ];
+        match ABI::decode(&data, true) {
+            Err(DecodingError::OverlappingData { param, offset }) => {
+                assert_eq!(param, 0);
+                assert_eq!(offset, 1);
+            }
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_doesnt_panic_on_random_input() {
+        let schemas = [
+            "1B",
+            "1S",
+            "1SB",
+            "1abBSiu",
+            "1uuuuu",
+            "1SSSSSSSSSSSSSSSSSSSSSSSSSSSSSS",
+        ];
+        for _ in 0..1000 {
+            let schema = schemas[thread_rng().gen_range(0..schemas.len())];
+            let len = thread_rng().gen_range(0..16);
+            let mut data: Vec<U256> = vec![encode::str_chunk32(schema).unwrap()];
+            for _ in 0..len {
+                let word = match thread_rng().gen_range(0..4) {
+                    0 => U256::from(thread_rng().gen_range(0..0x300)),
+                    1 => U256::from(thread_rng().gen_range(0..24) * 0x20),
+                    2 => U256::MAX - U256::from(thread_rng().gen_range(0..0x300)),
+                    _ => U256::from(into32(&rand_vec(32))),
+                };
+                data.push(word);
+            }
+            let _ = ABI::decode(&data, thread_rng().gen());
+        }
+    }
+
+    #[test]
+    fn it_decodes_from_hex() {
+        let input = concat!(
+            "0x",
+            "3153000000000000000000000000000000000000000000000000000000000000",
+            "54657374537472696e674e616d65000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000060",
+            "0000000000000000000000000000000000000000000000000000000000000011",
+            "536f6d6520737472696e672076616c7565000000000000000000000000000000",
+        );
+        let expected = ABI::only(Param::String {
+            name: "TestStringName".to_owned(),
+            value: "Some string value".to_owned(),
+        });
+        assert_eq!(ABI::from_hex(input, true).unwrap(), expected);
+        assert_eq!(ABI::from_hex(&input[2..], true).unwrap(), expected);
+        assert_eq!(expected.to_hex().unwrap(), input);
+    }
+
+    #[test]
+    fn it_encodes_decodes_bytes_roundtrip() {
+        let param = Param::Bytes {
+            name: rand_str(),
+            value: rand_vec(75),
+        };
+        let abi = ABI::new(vec![
+            Param::Uint256 {
+                name: rand_str(),
+                value: U256::from(1000),
+            },
+            param,
+        ]);
+        let bytes = abi.to_bytes().unwrap();
+        assert_eq!(bytes.len(), 32 * (1 + 4 + 1 + 3));
+        assert_eq!(bytes, chunks_to_bytes(&abi.encode().unwrap()));
+        assert_eq!(ABI::from_bytes(&bytes, true).unwrap(), abi);
+    }
+
+    #[test]
+    fn it_shouldnt_decode_unaligned_bytes() {
+        let bytes = ABI::none().to_bytes().unwrap();
+        match ABI::from_bytes(&bytes[..31], true) {
+            Err(DecodingError::InvalidInputSize(31)) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+        match ABI::from_bytes(&[], true) {
+            Err(DecodingError::NoInput) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_shouldnt_decode_invalid_hex() {
+        match ABI::from_hex("0x31zz", true) {
+            Err(DecodingError::InvalidHex(_)) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+        match ABI::from_hex("0x313", true) {
+            Err(DecodingError::InvalidHex(_)) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
     #[test]
     #[should_panic]
     fn it_shouldnt_decode_zero() {
@@ -872,4 +1761,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn it_gets_typed_values() {
+        let abi = ABI::new(vec![
+            Param::String32 {
+                name: "from".to_owned(),
+                value: "ETH".to_owned(),
+            },
+            Param::Uint256 {
+                name: "_times".to_owned(),
+                value: U256::from(100),
+            },
+            Param::String32 {
+                name: "from".to_owned(),
+                value: "BTC".to_owned(),
+            },
+        ]);
+        assert_eq!(abi.get_string("from"), Ok("ETH"));
+        assert_eq!(abi.get_u256("_times"), Ok(U256::from(100)));
+        assert_eq!(
+            abi.get_u256("from"),
+            Err(ParamError::TypeMismatch {
+                name: "from".to_owned(),
+                expected: "uint256".to_owned(),
+                actual: "string32".to_owned(),
+            })
+        );
+        assert_eq!(
+            abi.get_address("to"),
+            Err(ParamError::Missing("to".to_owned()))
+        );
+        // names are searched in the new parameters
+        let mut params = abi.into_params();
+        params.remove(0);
+        let abi = ABI::new(params);
+        assert_eq!(abi.get_string("from"), Ok("BTC"));
+        assert_eq!(abi.get_u256("_times"), Ok(U256::from(100)));
+        // and in the deserialized ones
+        let mut json = serde_json::to_value(&abi).unwrap();
+        json["version"] = 0x31.into();
+        let abi: ABI = serde_json::from_value(json).unwrap();
+        assert_eq!(abi.get_string("from"), Ok("BTC"));
+        assert_eq!(abi.get_u256("_times"), Ok(U256::from(100)));
+    }
 }
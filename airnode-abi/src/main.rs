@@ -0,0 +1,364 @@
+use airnode_abi::{DecodeOptions, Param, ABI};
+use anyhow::{anyhow, bail};
+use ethereum_types::U256;
+use serde_json::json;
+use std::io::Read;
+use std::str::FromStr;
+use structopt::StructOpt;
+
+#[derive(Debug, Clone, Copy)]
+enum Format {
+    Json,
+    Table,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        match src {
+            "json" => Ok(Self::Json),
+            "table" => Ok(Self::Table),
+            _ => Err(anyhow!("unknown format {}, json or table is expected", src)),
+        }
+    }
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "airnode-abi", about = "API3 Airnode ABI encoder/decoder")]
+enum Command {
+    /// Encodes parameters into hex string
+    Encode {
+        /// JSON list of parameters in the format of Airnode tooling, `-` to read it from stdin
+        #[structopt(long)]
+        json: Option<String>,
+        /// Print 32 bytes words, one per line
+        #[structopt(long)]
+        words: bool,
+        /// Parameters as `name:type=value`, e.g. `from:string32=ETH`
+        params: Vec<String>,
+    },
+    /// Decodes parameters from hex string
+    Decode {
+        /// Hex string of the parameters, read from stdin if it is missing or `-`
+        input: Option<String>,
+        /// Do not decode `Bytes32` and `String32` into booleans and dates
+        #[structopt(long, conflicts_with = "interpret")]
+        strict: bool,
+        /// Comma-separated list of interpreters of `Bytes32` and `String32` values, in the order
+        /// they are tried: bool, date, datetime, address, number, string32
+        #[structopt(long)]
+        interpret: Option<String>,
+        /// Decode `B` schema character as `String32`, like the legacy versions of this library
+        #[structopt(long)]
+        legacy: bool,
+        /// Input is a transaction input, parameters are taken from its `bytes` argument
+        #[structopt(long)]
+        tx: bool,
+        /// Output format: json or table
+        #[structopt(long, default_value = "table")]
+        format: Format,
+    },
+    /// Explains schema string, like `1bsS`
+    Schema { schema: String },
+}
+
+/// returns the argument, or the trimmed standard input if it is missing or `-`
+fn arg_or_stdin(arg: Option<String>) -> anyhow::Result<String> {
+    match arg {
+        Some(x) if x != "-" => Ok(x),
+        _ => {
+            let mut buf = String::new();
+            std::io::stdin().read_to_string(&mut buf)?;
+            Ok(buf.trim().to_owned())
+        }
+    }
+}
+
+/// parses parameter from `name:type=value` argument
+fn parse_param(src: &str, index: usize) -> anyhow::Result<Param> {
+    let (name, rest) = match src.find(':') {
+        Some(pos) => (&src[..pos], &src[pos + 1..]),
+        None => bail!("parameter {}: name:type=value is expected", src),
+    };
+    let (kind, value) = match rest.find('=') {
+        Some(pos) => (&rest[..pos], &rest[pos + 1..]),
+        None => bail!("parameter {}: name:type=value is expected", src),
+    };
+    let src = json!({"type": kind, "name": name, "value": value});
+    Ok(Param::from_json(&src, index)?)
+}
+
+fn encode(json: Option<String>, words: bool, params: Vec<String>) -> anyhow::Result<()> {
+    let abi = if json.is_some() || params.is_empty() {
+        ABI::from_json_str(&arg_or_stdin(json)?)?
+    } else {
+        let list = params
+            .iter()
+            .enumerate()
+            .map(|(i, p)| parse_param(p, i))
+            .collect::<anyhow::Result<Vec<Param>>>()?;
+        ABI::new(list)
+    };
+    abi.validate()?;
+    if words {
+        for word in abi.encode()? {
+            println!("0x{:064x}", word);
+        }
+    } else {
+        println!("{}", abi.to_hex()?);
+    }
+    Ok(())
+}
+
+/// returns decoding options with the interpreters from the comma-separated list,
+/// or the default ones
+fn decode_options(strict: bool, interpret: Option<&str>) -> anyhow::Result<DecodeOptions> {
+    let list = match interpret {
+        Some(_) if strict => bail!("--strict and --interpret could not be used together"),
+        Some(x) => x,
+        None if strict => return Ok(DecodeOptions::strict()),
+        None => return Ok(DecodeOptions::default()),
+    };
+    let mut options = DecodeOptions::strict();
+    for name in list.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+        options = match options.with_builtin(name) {
+            Some(x) => x,
+            None => bail!("unknown interpreter {}", name),
+        };
+    }
+    Ok(options)
+}
+
+/// decodes the first `bytes` argument of the call, that contains valid Airnode ABI.
+/// AirnodeRrp calls have the parameters as their only `bytes` argument
+fn decode_call(input: &[u8], options: &DecodeOptions) -> anyhow::Result<ABI> {
+    // skipping 4 bytes of the function selector
+    let args = match input.get(4..) {
+        Some(x) if x.len() % 32 == 0 => x,
+        _ => bail!("invalid transaction input size {}", input.len()),
+    };
+    let words: Vec<U256> = args.chunks(32).map(U256::from_big_endian).collect();
+    for word in &words {
+        // the word could be an offset of the dynamic argument
+        if *word >= U256::from(args.len()) || word.low_u64() % 32 != 0 {
+            continue;
+        }
+        let start = word.as_usize() + 32;
+        let len = words[word.as_usize() / 32];
+        if len > U256::from(args.len() - start) || len.is_zero() {
+            continue;
+        }
+        // other words could point to the data, that is decoded without errors too,
+        // so the parameters must be encoded back into the same bytes
+        let bytes = &args[start..start + len.as_usize()];
+        let exact = ABI::from_bytes(bytes, true)
+            .ok()
+            .and_then(|abi| abi.to_bytes().ok());
+        if exact.as_deref() == Some(bytes) {
+            return Ok(ABI::from_bytes_with_options(bytes, options)?);
+        }
+    }
+    bail!("no Airnode ABI parameters found in the transaction input")
+}
+
+fn print_table(abi: &ABI) {
+    let rows: Vec<(&str, &str, String)> = abi
+        .params()
+        .iter()
+        .map(|p| {
+            let value = p.to_json()["value"].as_str().unwrap_or_default().to_owned();
+            (p.get_name(), p.get_type(), value)
+        })
+        .collect();
+    let name_width = rows.iter().map(|r| r.0.len()).max().unwrap_or(0).max(4);
+    let type_width = rows.iter().map(|r| r.1.len()).max().unwrap_or(0).max(4);
+    println!("schema: {}", abi.schema);
+    println!(
+        "{:nw$}  {:tw$}  value",
+        "name",
+        "type",
+        nw = name_width,
+        tw = type_width
+    );
+    for (name, kind, value) in rows {
+        println!(
+            "{:nw$}  {:tw$}  {}",
+            name,
+            kind,
+            value,
+            nw = name_width,
+            tw = type_width
+        );
+    }
+}
+
+/// decodes parameters from hex string of the parameters or of the transaction input
+fn decode_input(input: &str, options: &DecodeOptions, tx: bool) -> anyhow::Result<ABI> {
+    let digits = input.strip_prefix("0x").unwrap_or(input);
+    let bytes = hex::decode(digits).map_err(|e| anyhow!("invalid hex string: {}", e))?;
+    if tx {
+        decode_call(&bytes, options)
+    } else {
+        Ok(ABI::from_bytes_with_options(&bytes, options)?)
+    }
+}
+
+fn decode(
+    input: Option<String>,
+    options: DecodeOptions,
+    tx: bool,
+    format: Format,
+) -> anyhow::Result<()> {
+    let abi = decode_input(&arg_or_stdin(input)?, &options, tx)?;
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(&abi.to_json())?),
+        Format::Table => print_table(&abi),
+    }
+    Ok(())
+}
+
+/// returns name of the type and the size of the value for the schema character
+fn describe_char(ch: char) -> Option<(&'static str, &'static str)> {
+    match ch {
+        'a' => Some(("address", "static, 32 bytes")),
+        'b' => Some(("bytes32", "static, 32 bytes")),
+        'B' => Some(("bytes", "dynamic")),
+        's' => Some(("string32", "static, 32 bytes")),
+        'S' => Some(("string", "dynamic")),
+        'i' => Some(("int256", "static, 32 bytes")),
+        'u' => Some(("uint256", "static, 32 bytes")),
+        _ => None,
+    }
+}
+
+fn schema(src: &str) -> anyhow::Result<()> {
+    let mut chars = src.chars();
+    match chars.next() {
+        Some('1') => println!("version 1"),
+        Some(x) => bail!("unknown schema version {}", x),
+        None => bail!("schema is empty"),
+    }
+    for (index, ch) in chars.enumerate() {
+        match describe_char(ch) {
+            Some((kind, size)) => println!("{}: {} {} ({})", index + 1, ch, kind, size),
+            None => bail!("invalid schema character {} at position {}", ch, index + 1),
+        }
+    }
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    match Command::from_args() {
+        Command::Encode {
+            json,
+            words,
+            params,
+        } => encode(json, words, params),
+        Command::Decode {
+            input,
+            strict,
+            interpret,
+            legacy,
+            tx,
+            format,
+        } => {
+            let options = decode_options(strict, interpret.as_deref())?.legacy(legacy);
+            decode(input, options, tx, format)
+        }
+        Command::Schema { schema: s } => schema(&s),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn string32(name: &str, value: &str) -> Param {
+        Param::String32 {
+            name: name.to_owned(),
+            value: value.to_owned(),
+        }
+    }
+
+    #[test]
+    fn it_parses_params() {
+        assert_eq!(
+            parse_param("from:string32=ETH", 0).unwrap(),
+            string32("from", "ETH")
+        );
+        // only the first `=` separates the value
+        assert_eq!(
+            parse_param("q:string=a=b", 0).unwrap(),
+            Param::String {
+                name: "q".to_owned(),
+                value: "a=b".to_owned()
+            }
+        );
+        assert!(parse_param("from=ETH", 0).is_err());
+        assert!(parse_param("from:string32", 0).is_err());
+        assert!(parse_param("from:float=1.5", 0).is_err());
+    }
+
+    #[test]
+    fn it_builds_decode_options() {
+        let input = ABI::new(vec![string32("day", "2021-07-01"), string32("n", "42")])
+            .to_hex()
+            .unwrap();
+        let decoded = |strict, interpret| {
+            let options = decode_options(strict, interpret).unwrap();
+            decode_input(&input, &options, false).unwrap().into_params()
+        };
+        let params = decoded(false, None);
+        assert!(matches!(params[0], Param::Date { .. }));
+        assert_eq!(params[1], string32("n", "42"));
+        let params = decoded(true, None);
+        assert_eq!(params[0], string32("day", "2021-07-01"));
+        let params = decoded(false, Some("number, "));
+        assert_eq!(params[0], string32("day", "2021-07-01"));
+        assert!(matches!(params[1], Param::Uint256 { .. }));
+        assert!(decode_options(false, Some("bool,float")).is_err());
+        assert!(decode_options(true, Some("bool")).is_err());
+    }
+
+    #[test]
+    fn it_finds_parameters_in_transaction_input() {
+        let params = ABI::new(vec![string32("from", "ETH")]).to_bytes().unwrap();
+        let word = |x: usize| {
+            let mut w = [0u8; 32];
+            U256::from(x).to_big_endian(&mut w);
+            w.to_vec()
+        };
+        // f(uint256 count, bytes parameters) with the count, that looks like an offset
+        let mut input = hex::decode("a9059cbb").unwrap();
+        input.extend(word(0x20));
+        input.extend(word(0x40));
+        input.extend(word(params.len()));
+        input.extend(&params);
+        let options = DecodeOptions::strict();
+        let abi = decode_call(&input, &options).unwrap();
+        assert_eq!(abi.params(), vec![string32("from", "ETH")]);
+        let hex_input = format!("0x{}", hex::encode(&input));
+        assert_eq!(decode_input(&hex_input, &options, true).unwrap(), abi);
+
+        assert!(decode_call(&input[..3], &options).is_err());
+        assert!(decode_call(&input[..input.len() - 1], &options).is_err());
+        // the same call without parameters
+        let empty = [&input[..4], &word(1)[..], &word(0x40)[..], &word(0)[..]].concat();
+        assert!(decode_call(&empty, &options).is_err());
+    }
+
+    #[test]
+    fn it_rejects_strict_with_interpreters() {
+        let res = Command::from_iter_safe(&[
+            "airnode-abi",
+            "decode",
+            "--strict",
+            "--interpret",
+            "bool",
+            "0x",
+        ]);
+        assert!(res.is_err());
+        assert!(Command::from_iter_safe(&["airnode-abi", "decode", "--strict", "0x"]).is_ok());
+    }
+}
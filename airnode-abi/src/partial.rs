@@ -0,0 +1,162 @@
+use crate::prelude::*;
+use crate::{Cursor, DecodeOptions, DecodingError, Param, ABI};
+use core::fmt;
+use ethereum_types::U256;
+use serde::{Deserialize, Serialize};
+
+/// Parameter that could not be decoded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParamFailure {
+    /// position of the parameter in the schema
+    pub index: usize,
+    /// schema character of the parameter
+    pub schema_char: char,
+    /// reason of the failure
+    pub error: DecodingError,
+}
+
+impl fmt::Display for ParamFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{}: {}", self.schema_char, self.index, self.error)
+    }
+}
+
+/// Result of the best-effort decoding: parameters that were decoded,
+/// failures of the other parameters and the words that were not read at all
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartialABI {
+    /// Schema string, as it was found in the input
+    pub schema: String,
+    /// Parameters that were decoded successfully
+    pub params: Vec<Param>,
+    /// Parameters that failed to decode
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub failures: Vec<ParamFailure>,
+    /// Words after the end of the encoded parameters
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub trailing: Vec<U256>,
+}
+
+impl PartialABI {
+    /// returns whether all parameters were decoded and there is no trailing data
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty() && self.trailing.is_empty()
+    }
+
+    /// returns ABI with the decoded parameters
+    pub fn to_abi(&self) -> ABI {
+        ABI::new(self.params.clone())
+    }
+}
+
+impl ABI {
+    /// decodes as many parameters as possible from the vector or 256 bit values.
+    /// Fails only if the schema cannot be read, errors of the parameters
+    /// are collected into `PartialABI::failures` together with their positions.
+    pub fn decode_partial(
+        input: &[U256],
+        options: &DecodeOptions,
+    ) -> Result<PartialABI, DecodingError> {
+        let schema = Self::decode_schema(input)?;
+        let chars: Vec<char> = schema.chars().skip(1).collect();
+        let mut cursor = Cursor::new(input, 1 + 2 * chars.len());
+        let mut params = vec![];
+        let mut failures = vec![];
+        for (index, ch) in chars.into_iter().enumerate() {
+            match Self::from_chunks(ch, &mut cursor, index, options) {
+                Ok(p) => params.push(p),
+                Err(error) => failures.push(ParamFailure {
+                    index,
+                    schema_char: ch,
+                    error,
+                }),
+            }
+        }
+        let trailing = input.iter().skip(cursor.end()).cloned().collect();
+        Ok(PartialABI {
+            schema,
+            params,
+            failures,
+            trailing,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encode::str_chunk32;
+
+    fn sample() -> Vec<U256> {
+        ABI::new(vec![
+            Param::String32 {
+                name: "from".to_owned(),
+                value: "ETH".to_owned(),
+            },
+            Param::String {
+                name: "_path".to_owned(),
+                value: "data.price".to_owned(),
+            },
+            Param::Uint256 {
+                name: "_times".to_owned(),
+                value: U256::from(100),
+            },
+        ])
+        .encode()
+        .unwrap()
+    }
+
+    #[test]
+    fn it_decodes_complete_input() {
+        let res = ABI::decode_partial(&sample(), &DecodeOptions::default()).unwrap();
+        assert!(res.is_complete());
+        assert_eq!(res.to_abi(), ABI::decode(&sample(), false).unwrap());
+    }
+
+    #[test]
+    fn it_keeps_good_params() {
+        let mut data = sample();
+        // invalid UTF-8 in the value of the first parameter
+        data[2] = U256::MAX;
+        // offset of the string points outside of the input
+        data[4] = U256::from(0x1000);
+        data.push(U256::from(7));
+        assert!(ABI::decode(&data, false).is_err());
+        let res = ABI::decode_partial(&data, &DecodeOptions::default()).unwrap();
+        assert_eq!(res.schema, "1sSu");
+        assert_eq!(
+            res.params,
+            vec![Param::Uint256 {
+                name: "_times".to_owned(),
+                value: U256::from(100),
+            }]
+        );
+        assert_eq!(res.failures.len(), 2);
+        assert_eq!(res.failures[0].index, 0);
+        assert_eq!(res.failures[0].schema_char, 's');
+        assert_eq!(res.failures[1].index, 1);
+        assert_eq!(
+            res.failures[1].error,
+            DecodingError::InvalidOffset {
+                param: 1,
+                offset: 4
+            }
+        );
+        // the string data is not referenced anymore
+        assert_eq!(res.trailing.len(), 3);
+        assert_eq!(res.trailing[2], U256::from(7));
+    }
+
+    #[test]
+    fn it_reports_truncated_params() {
+        let data = vec![str_chunk32("1uu").unwrap(), str_chunk32("a").unwrap()];
+        let res = ABI::decode_partial(&data, &DecodeOptions::strict()).unwrap();
+        assert!(res.params.is_empty());
+        assert_eq!(res.failures.len(), 2);
+        assert_eq!(
+            res.failures[1].to_string(),
+            "u#1: parameter 1: input is truncated at word 3"
+        );
+        assert!(ABI::decode_partial(&[], &DecodeOptions::strict()).is_err());
+    }
+}
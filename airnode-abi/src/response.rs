@@ -0,0 +1,945 @@
+use crate::decimal::{Decimal, DecimalError};
+use crate::decode::{chunk_to_address, chunk_to_int, chunk_to_str, chunk_to_vec};
+use crate::encode::{address_chunk, chunks, chunks_to_bytes, int_chunk, str_chunk32};
+use crate::prelude::*;
+use crate::{Param, ABI, I256};
+use core::fmt;
+use core::str::FromStr;
+use ethereum_types::{H160, U256};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum ResponseError {
+    #[error("invalid type {0}")]
+    InvalidType(String),
+    #[error("invalid _times {0}")]
+    InvalidTimes(String),
+    #[error("number of _path or _times values doesn't match the number of types")]
+    MismatchedCount,
+    #[error("no value at path {0}")]
+    PathNotFound(String),
+    #[error("cannot convert {value} into {kind}: {reason}")]
+    InvalidValue {
+        kind: String,
+        value: String,
+        reason: String,
+    },
+    #[error("data is truncated at word {0}")]
+    UnexpectedEnd(usize),
+    #[error("invalid {kind} at word {offset}")]
+    InvalidData { kind: String, offset: usize },
+    #[error("values overlap: more words are decoded than data has, at word {0}")]
+    Overlapping(usize),
+}
+
+/// Type of the response value, as it is specified in `_type` reserved parameter
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseType {
+    Int256,
+    Uint256,
+    Bool,
+    Bytes32,
+    String32,
+    Address,
+    Bytes,
+    String,
+    /// unix timestamp of the moment of processing, encoded as `uint256`
+    Timestamp,
+    /// array of the values, with fixed size or dynamic (if the size is `None`)
+    Array(Box<ResponseType>, Option<usize>),
+}
+
+impl ResponseType {
+    /// returns whether the size of the encoded value is dynamic
+    fn is_dynamic(&self) -> bool {
+        match &self {
+            Self::Bytes | Self::String => true,
+            Self::Array(inner, size) => size.is_none() || inner.is_dynamic(),
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for ResponseType {
+    type Err = ResponseError;
+
+    /// parses type like `int256`, `string32[]` or `uint256[2][]`
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let invalid = || ResponseError::InvalidType(src.to_owned());
+        let src = src.trim();
+        let (base, mut suffix) = match src.find('[') {
+            Some(pos) => (&src[..pos], &src[pos..]),
+            None => (src, ""),
+        };
+        let mut out = match base {
+            "int256" => Self::Int256,
+            "uint256" => Self::Uint256,
+            "bool" => Self::Bool,
+            "bytes32" => Self::Bytes32,
+            "string32" => Self::String32,
+            "address" => Self::Address,
+            "bytes" => Self::Bytes,
+            "string" => Self::String,
+            "timestamp" => Self::Timestamp,
+            _ => return Err(invalid()),
+        };
+        while !suffix.is_empty() {
+            let end = match (suffix.starts_with('['), suffix.find(']')) {
+                (true, Some(end)) => end,
+                _ => return Err(invalid()),
+            };
+            let size = match &suffix[1..end] {
+                "" => None,
+                x => match x.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(invalid()),
+                },
+            };
+            out = Self::Array(Box::new(out), size);
+            suffix = &suffix[end + 1..];
+        }
+        Ok(out)
+    }
+}
+
+impl fmt::Display for ResponseType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Self::Int256 => write!(f, "int256"),
+            Self::Uint256 => write!(f, "uint256"),
+            Self::Bool => write!(f, "bool"),
+            Self::Bytes32 => write!(f, "bytes32"),
+            Self::String32 => write!(f, "string32"),
+            Self::Address => write!(f, "address"),
+            Self::Bytes => write!(f, "bytes"),
+            Self::String => write!(f, "string"),
+            Self::Timestamp => write!(f, "timestamp"),
+            Self::Array(inner, Some(size)) => write!(f, "{}[{}]", inner, size),
+            Self::Array(inner, None) => write!(f, "{}[]", inner),
+        }
+    }
+}
+
+/// Value decoded from the `data` of the fulfillment
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseValue {
+    Int256(I256),
+    Uint256(U256),
+    /// `int256` or `uint256` value, divided by `_times`
+    Decimal(Decimal),
+    Bool(bool),
+    Bytes32(U256),
+    String32(String),
+    Address(H160),
+    Bytes(Vec<u8>),
+    String(String),
+    Timestamp(U256),
+    Array(Vec<ResponseValue>),
+}
+
+impl fmt::Display for ResponseValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Self::Int256(x) => write!(f, "{}", x),
+            Self::Uint256(x) => write!(f, "{}", x),
+            Self::Decimal(x) => write!(f, "{}", x),
+            Self::Bool(x) => write!(f, "{}", x),
+            Self::Bytes32(x) => write!(f, "0x{:064x}", x),
+            Self::String32(x) => write!(f, "{}", x),
+            Self::Address(x) => write!(f, "{:?}", x),
+            Self::Bytes(x) => write!(f, "0x{}", hex::encode(x)),
+            Self::String(x) => write!(f, "{}", x),
+            Self::Timestamp(x) => write!(f, "{}", x),
+            Self::Array(items) => {
+                let items: Vec<String> = items.iter().map(|x| x.to_string()).collect();
+                write!(f, "[{}]", items.join(", "))
+            }
+        }
+    }
+}
+
+/// Reserved parameters of the Airnode endpoint, that define processing of the API response.
+/// Multiple values are encoded when `_type` contains comma-separated list of types,
+/// `_path` and `_times` should contain the same number of comma-separated values then.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReservedParameters {
+    /// path to the value in the response, keys are separated by dots.
+    /// Dots and commas that are part of the key should be escaped with backslash
+    #[serde(rename = "_path")]
+    pub path: Option<String>,
+    /// multiplier that is applied to `int256` and `uint256` values.
+    /// The result is truncated towards zero
+    #[serde(rename = "_times")]
+    pub times: Option<String>,
+    /// type (or comma-separated list of types) of the encoded value
+    #[serde(rename = "_type")]
+    pub kind: String,
+}
+
+impl ReservedParameters {
+    /// extracts, converts and encodes value(s) from the API response.
+    /// `timestamp` is a unix time that is used for `timestamp` type.
+    /// Returns bytes that Airnode would submit as `data` of the fulfillment
+    pub fn encode_response(
+        &self,
+        response: &Value,
+        timestamp: u64,
+    ) -> Result<Vec<u8>, ResponseError> {
+        let types = self.types()?;
+        let paths: Vec<Option<String>> = match &self.path {
+            Some(p) => split_escaped(p, ',').into_iter().map(Some).collect(),
+            None => vec![None; types.len()],
+        };
+        let times = self.times(types.len())?;
+        if paths.len() != types.len() {
+            return Err(ResponseError::MismatchedCount);
+        }
+        let mut tokens = vec![];
+        for (i, kind) in types.iter().enumerate() {
+            let token = if *kind == ResponseType::Timestamp {
+                Token::Word(U256::from(timestamp))
+            } else {
+                let value = match &paths[i] {
+                    Some(path) => get_by_path(response, path)?,
+                    None => response,
+                };
+                to_token(kind, value, times[i].as_ref())?
+            };
+            tokens.push((kind.is_dynamic(), token));
+        }
+        Ok(chunks_to_bytes(&encode_tuple(&tokens)))
+    }
+
+    /// decodes `data` of the fulfillment back into the typed values.
+    /// `int256` and `uint256` values are divided by `_times` (if it is set)
+    /// and returned as decimals then
+    pub fn decode_response(&self, data: &[U256]) -> Result<Vec<ResponseValue>, ResponseError> {
+        let types = self.types()?;
+        let times = self.times(types.len())?;
+        let items: Vec<(&ResponseType, Option<&Decimal>)> = types
+            .iter()
+            .zip(times.iter())
+            .map(|(kind, t)| (kind, t.as_ref()))
+            .collect();
+        // offsets could point to the same words, so decoding of nested arrays is limited
+        // to the number of words in the data, which is enough for the values that don't overlap
+        let mut budget = data.len();
+        decode_tuple(data, 0, &items, &mut budget)
+    }
+
+    /// reads reserved parameters from the parameters of the request or template.
+    /// Returns `None` if there is no `_type` parameter
+    pub fn from_abi(abi: &ABI) -> Option<Self> {
+        let text = |key: &str| match abi.get(key) {
+            Some(Param::String32 { value, .. }) => Some(value),
+            Some(Param::String { value, .. }) => Some(value),
+            _ => None,
+        };
+        Some(Self {
+            path: text("_path"),
+            times: text("_times"),
+            kind: text("_type")?,
+        })
+    }
+
+    /// parses the list of types from `_type`
+    fn types(&self) -> Result<Vec<ResponseType>, ResponseError> {
+        split_escaped(&self.kind, ',')
+            .iter()
+            .map(|t| ResponseType::from_str(t))
+            .collect()
+    }
+
+    /// parses the list of multipliers from `_times`, that should match the number of types
+    fn times(&self, count: usize) -> Result<Vec<Option<Decimal>>, ResponseError> {
+        let times: Vec<Option<Decimal>> = match &self.times {
+            Some(t) => t
+                .split(',')
+                .map(|x| match x.trim() {
+                    "" => Ok(None),
+                    x => match Decimal::from_str(x) {
+                        Ok(d) => Ok(Some(d)),
+                        Err(_) => Err(ResponseError::InvalidTimes(x.to_owned())),
+                    },
+                })
+                .collect::<Result<_, _>>()?,
+            None => vec![None; count],
+        };
+        if times.len() != count {
+            return Err(ResponseError::MismatchedCount);
+        }
+        Ok(times)
+    }
+}
+
+/// splits string by the separator, that is not escaped with backslash.
+/// Escape sequences are kept in the parts
+fn split_escaped(src: &str, separator: char) -> Vec<String> {
+    let mut out = vec![];
+    let mut current = String::new();
+    let mut chars = src.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            current.push(c);
+            if let Some(next) = chars.next() {
+                current.push(next);
+            }
+        } else if c == separator {
+            out.push(current);
+            current = String::new();
+        } else {
+            current.push(c);
+        }
+    }
+    out.push(current);
+    out
+}
+
+/// removes escaping backslashes
+fn unescape(src: &str) -> String {
+    let mut out = String::new();
+    let mut chars = src.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// returns value from the response by the dotted path,
+/// numeric keys are used as indexes of arrays. Empty path refers to the whole response
+fn get_by_path<'a>(response: &'a Value, path: &str) -> Result<&'a Value, ResponseError> {
+    if path.trim().is_empty() {
+        return Ok(response);
+    }
+    let mut current = response;
+    for key in split_escaped(path, '.').iter().map(|k| unescape(k)) {
+        let next = match current {
+            Value::Object(obj) => obj.get(&key),
+            Value::Array(arr) => key.parse::<usize>().ok().and_then(|i| arr.get(i)),
+            _ => None,
+        };
+        current = match next {
+            Some(x) => x,
+            None => return Err(ResponseError::PathNotFound(unescape(path))),
+        };
+    }
+    Ok(current)
+}
+
+/// Value prepared for the standard ABI encoding
+enum Token {
+    /// static 32 bytes value
+    Word(U256),
+    /// bytes or string, with the length prefix
+    Bytes(Vec<u8>),
+    /// array with the length prefix
+    Array(Vec<(bool, Token)>),
+    /// array of fixed size, encoded like a tuple
+    FixedArray(Vec<(bool, Token)>),
+}
+
+/// encodes list of tokens as a tuple: heads of all values, followed by the dynamic data.
+/// Each token comes with the flag, whether it is dynamic
+fn encode_tuple(tokens: &[(bool, Token)]) -> Vec<U256> {
+    let heads: Vec<Vec<U256>> = tokens
+        .iter()
+        .map(|(dynamic, t)| {
+            if *dynamic {
+                vec![U256::zero()]
+            } else {
+                encode_token(t)
+            }
+        })
+        .collect();
+    let mut offset: usize = heads.iter().map(|h| h.len()).sum::<usize>() * 32;
+    let mut out = vec![];
+    let mut tails = vec![];
+    for (i, (dynamic, token)) in tokens.iter().enumerate() {
+        if *dynamic {
+            let tail = encode_token(token);
+            out.push(U256::from(offset));
+            offset += tail.len() * 32;
+            tails.extend(tail);
+        } else {
+            out.extend(heads[i].iter().cloned());
+        }
+    }
+    out.extend(tails);
+    out
+}
+
+fn encode_token(token: &Token) -> Vec<U256> {
+    match token {
+        Token::Word(w) => vec![*w],
+        Token::Bytes(b) => {
+            let mut out = vec![U256::from(b.len())];
+            out.extend(chunks(b));
+            out
+        }
+        Token::Array(items) => {
+            let mut out = vec![U256::from(items.len())];
+            out.extend(encode_tuple(items));
+            out
+        }
+        Token::FixedArray(items) => encode_tuple(items),
+    }
+}
+
+/// converts JSON value into the token of the given type
+fn to_token(
+    kind: &ResponseType,
+    value: &Value,
+    times: Option<&Decimal>,
+) -> Result<Token, ResponseError> {
+    let invalid = |reason: &str| ResponseError::InvalidValue {
+        kind: kind.to_string(),
+        value: value.to_string(),
+        reason: reason.to_owned(),
+    };
+    match kind {
+        ResponseType::Int256 | ResponseType::Uint256 => {
+            let mut num = match value {
+                Value::Number(n) => Decimal::from_str(&n.to_string()),
+                Value::String(s) => Decimal::from_str(s.trim()),
+                Value::Bool(b) => Decimal::from_str(if *b { "1" } else { "0" }),
+                _ => Err(DecimalError::Invalid(value.to_string())),
+            }
+            .map_err(|_| invalid("not a number"))?;
+            if let Some(t) = times {
+                num = num.mul(t);
+            }
+            let word = if *kind == ResponseType::Int256 {
+                num.to_i256().map(int_chunk)
+            } else {
+                num.to_u256()
+            };
+            match word {
+                Some(w) => Ok(Token::Word(w)),
+                None => Err(invalid("value is out of range")),
+            }
+        }
+        ResponseType::Bool => {
+            let truthy = match value {
+                Value::Null => false,
+                Value::Bool(b) => *b,
+                Value::Number(n) => n.as_f64() != Some(0.0),
+                Value::String(s) => !(s.is_empty() || s == "false" || s == "0"),
+                _ => true,
+            };
+            Ok(Token::Word(U256::from(truthy as u8)))
+        }
+        ResponseType::Bytes32 => {
+            let bytes = hex_value(value).ok_or_else(|| invalid("not a hex string"))?;
+            if bytes.len() != 32 {
+                return Err(invalid("32 bytes are expected"));
+            }
+            Ok(Token::Word(U256::from_big_endian(&bytes)))
+        }
+        ResponseType::String32 => {
+            let s = text_value(value).ok_or_else(|| invalid("not a string"))?;
+            // strings are truncated to 31 bytes, keeping the trailing zero byte
+            let mut end = s.len().min(31);
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            Ok(Token::Word(str_chunk32(&s[..end]).unwrap()))
+        }
+        ResponseType::Address => {
+            let bytes = hex_value(value).ok_or_else(|| invalid("not a hex string"))?;
+            if bytes.len() != 20 {
+                return Err(invalid("20 bytes are expected"));
+            }
+            Ok(Token::Word(address_chunk(H160::from_slice(&bytes))))
+        }
+        ResponseType::Bytes => {
+            let bytes = hex_value(value).ok_or_else(|| invalid("not a hex string"))?;
+            Ok(Token::Bytes(bytes))
+        }
+        ResponseType::String => {
+            let s = text_value(value).ok_or_else(|| invalid("not a string"))?;
+            Ok(Token::Bytes(s.into_bytes()))
+        }
+        ResponseType::Timestamp => Err(invalid("timestamp is not taken from the response")),
+        ResponseType::Array(inner, size) => {
+            let items = match value.as_array() {
+                Some(x) => x,
+                None => return Err(invalid("not an array")),
+            };
+            if let Some(sz) = size {
+                if items.len() != *sz {
+                    return Err(invalid(&format!("{} items are expected", sz)));
+                }
+            }
+            let mut tokens = vec![];
+            for item in items {
+                tokens.push((inner.is_dynamic(), to_token(inner, item, times)?));
+            }
+            match size {
+                Some(_) => Ok(Token::FixedArray(tokens)),
+                None => Ok(Token::Array(tokens)),
+            }
+        }
+    }
+}
+
+/// returns the number of words in the head of the encoded tuple, that the value occupies
+fn head_size(kind: &ResponseType) -> usize {
+    match kind {
+        ResponseType::Array(inner, Some(size)) if !kind.is_dynamic() => head_size(inner) * size,
+        _ => 1,
+    }
+}
+
+/// takes `words` from the budget of the words, that are left to decode
+fn spend(budget: &mut usize, words: usize, pos: usize) -> Result<(), ResponseError> {
+    *budget = budget
+        .checked_sub(words)
+        .ok_or(ResponseError::Overlapping(pos))?;
+    Ok(())
+}
+
+/// decodes the tuple of values, which starts at word `start` of `data`.
+/// Offsets of the dynamic values are relative to the start of the tuple
+fn decode_tuple(
+    data: &[U256],
+    start: usize,
+    items: &[(&ResponseType, Option<&Decimal>)],
+    budget: &mut usize,
+) -> Result<Vec<ResponseValue>, ResponseError> {
+    let mut out = vec![];
+    let mut pos = start;
+    for (kind, times) in items {
+        if kind.is_dynamic() {
+            let word = *data.get(pos).ok_or(ResponseError::UnexpectedEnd(pos))?;
+            let invalid_offset = || ResponseError::InvalidData {
+                kind: "offset".to_owned(),
+                offset: pos,
+            };
+            if word > U256::from(data.len() * 32) || word.low_u64() % 32 != 0 {
+                return Err(invalid_offset());
+            }
+            let offset = start + word.as_usize() / 32;
+            if offset >= data.len() {
+                return Err(invalid_offset());
+            }
+            out.push(decode_value(data, offset, kind, *times, budget)?);
+        } else {
+            out.push(decode_value(data, pos, kind, *times, budget)?);
+        }
+        pos += head_size(kind);
+    }
+    Ok(out)
+}
+
+/// decodes single value of the given type, that is located at word `pos` of `data`.
+/// Words of the value are taken from the `budget`, arrays of fixed size take only the words of their items
+fn decode_value(
+    data: &[U256],
+    pos: usize,
+    kind: &ResponseType,
+    times: Option<&Decimal>,
+    budget: &mut usize,
+) -> Result<ResponseValue, ResponseError> {
+    let word = *data.get(pos).ok_or(ResponseError::UnexpectedEnd(pos))?;
+    if !matches!(kind, ResponseType::Array(_, Some(_))) {
+        spend(budget, 1, pos)?;
+    }
+    let invalid = || ResponseError::InvalidData {
+        kind: kind.to_string(),
+        offset: pos,
+    };
+    match kind {
+        ResponseType::Int256 | ResponseType::Uint256 => {
+            let signed = *kind == ResponseType::Int256;
+            let t = match times {
+                Some(t) => t,
+                None if signed => return Ok(ResponseValue::Int256(chunk_to_int(word))),
+                None => return Ok(ResponseValue::Uint256(word)),
+            };
+            let value = if signed {
+                Decimal::from_i256(chunk_to_int(word))
+            } else {
+                Decimal::from_u256(word)
+            };
+            match value.div(t) {
+                Some(x) => Ok(ResponseValue::Decimal(x)),
+                None => Err(ResponseError::InvalidTimes(t.to_string())),
+            }
+        }
+        ResponseType::Bool => {
+            if word > U256::one() {
+                return Err(invalid());
+            }
+            Ok(ResponseValue::Bool(!word.is_zero()))
+        }
+        ResponseType::Bytes32 => Ok(ResponseValue::Bytes32(word)),
+        ResponseType::String32 => match chunk_to_str(word) {
+            Ok(s) => Ok(ResponseValue::String32(s)),
+            Err(_) => Err(invalid()),
+        },
+        ResponseType::Address => Ok(ResponseValue::Address(chunk_to_address(word))),
+        ResponseType::Timestamp => Ok(ResponseValue::Timestamp(word)),
+        ResponseType::Bytes | ResponseType::String => {
+            // length of the data must fit into the remaining words
+            let available = data.len() - pos - 1;
+            if word > U256::from(available * 32) {
+                return Err(invalid());
+            }
+            spend(budget, word.as_usize().div_ceil(32), pos)?;
+            let bytes = chunk_to_vec(data, pos + 1, word.as_usize());
+            if *kind == ResponseType::Bytes {
+                return Ok(ResponseValue::Bytes(bytes));
+            }
+            match String::from_utf8(bytes) {
+                Ok(s) => Ok(ResponseValue::String(s)),
+                Err(_) => Err(invalid()),
+            }
+        }
+        ResponseType::Array(inner, size) => {
+            let (start, count) = match size {
+                Some(sz) => (pos, *sz),
+                None if word > U256::from(data.len()) => return Err(invalid()),
+                None => (pos + 1, word.as_usize()),
+            };
+            // every item occupies at least one word
+            if count > data.len() - start {
+                return Err(invalid());
+            }
+            let items = vec![(inner.as_ref(), times); count];
+            Ok(ResponseValue::Array(decode_tuple(
+                data, start, &items, budget,
+            )?))
+        }
+    }
+}
+
+/// returns bytes from 0x-prefixed hex string
+fn hex_value(value: &Value) -> Option<Vec<u8>> {
+    let s = value.as_str()?;
+    hex::decode(s.strip_prefix("0x")?).ok()
+}
+
+/// returns text of the string, number or boolean
+fn text_value(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::decode::bytes_to_chunks;
+    use hex_literal::hex;
+    use serde_json::json;
+
+    fn params(path: &str, times: &str, kind: &str) -> ReservedParameters {
+        ReservedParameters {
+            path: Some(path.to_owned()),
+            times: if times.is_empty() {
+                None
+            } else {
+                Some(times.to_owned())
+            },
+            kind: kind.to_owned(),
+        }
+    }
+
+    fn encode(response: &Value, path: &str, times: &str, kind: &str) -> Vec<U256> {
+        let res = params(path, times, kind)
+            .encode_response(response, 1600000000)
+            .unwrap();
+        bytes_to_chunks(&res)
+    }
+
+    #[test]
+    fn it_parses_types() {
+        for t in [
+            "int256",
+            "string32[]",
+            "uint256[2][]",
+            "bytes[][3]",
+            "timestamp",
+        ] {
+            assert_eq!(ResponseType::from_str(t).unwrap().to_string(), t);
+        }
+        for t in ["int", "uint256[", "uint256[0]", "uint256[x]", "bool]", ""] {
+            assert!(ResponseType::from_str(t).is_err(), "{}", t);
+        }
+    }
+
+    #[test]
+    fn it_gets_value_by_path() {
+        let response = json!({
+            "data": {"prices": [{"usd": 1.5}, {"usd": 2.5}]},
+            "a.b": {"c,d": "escaped"},
+        });
+        assert_eq!(
+            get_by_path(&response, "data.prices.1.usd").unwrap(),
+            &json!(2.5)
+        );
+        assert_eq!(get_by_path(&response, "").unwrap(), &response);
+        assert_eq!(
+            get_by_path(&response, "a\\.b.c\\,d").unwrap(),
+            &json!("escaped")
+        );
+        assert_eq!(
+            get_by_path(&response, "data.prices.2"),
+            Err(ResponseError::PathNotFound("data.prices.2".to_owned()))
+        );
+    }
+
+    #[test]
+    fn it_encodes_int256_with_times() {
+        let response = json!({"price": -123.4567891});
+        let res = encode(&response, "price", "1000000", "int256");
+        assert_eq!(res, vec![int_chunk(crate::I256::from(-123456789))]);
+    }
+
+    #[test]
+    fn it_encodes_uint256_from_string() {
+        let response = json!({"supply": "123456789012345678901234567890.987"});
+        let res = encode(&response, "supply", "1000", "uint256");
+        assert_eq!(
+            res,
+            vec![U256::from_dec_str("123456789012345678901234567890987").unwrap()]
+        );
+    }
+
+    #[test]
+    fn it_fails_on_out_of_range() {
+        let response = json!({"price": -1});
+        let res = params("price", "", "uint256").encode_response(&response, 0);
+        match res {
+            Err(ResponseError::InvalidValue { .. }) => {}
+            x => panic!("unexpected result {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_encodes_bool_and_string32() {
+        let response = json!({"ok": "false", "name": "0123456789012345678901234567890123"});
+        let res = encode(&response, "ok,name", "", "bool,string32");
+        assert_eq!(
+            res,
+            vec![
+                U256::zero(),
+                str_chunk32("0123456789012345678901234567890").unwrap(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_encodes_timestamp() {
+        let res = encode(&json!({}), "", "", "timestamp");
+        assert_eq!(res, vec![U256::from(1600000000)]);
+    }
+
+    #[test]
+    fn it_encodes_string() {
+        let response = json!({"msg": "hello"});
+        let res = encode(&response, "msg", "", "string");
+        assert_eq!(
+            res,
+            vec![
+                U256::from(0x20),
+                U256::from(5),
+                hex!("68656c6c6f000000000000000000000000000000000000000000000000000000").into(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_encodes_arrays() {
+        let response = json!({"values": [[1.5, 2], [3, 4.75]]});
+        let res = encode(&response, "values", "100", "uint256[2][]");
+        let expected: Vec<U256> = vec![0x20, 2, 150, 200, 300, 475]
+            .into_iter()
+            .map(U256::from)
+            .collect();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn it_encodes_dynamic_arrays() {
+        let response = json!(["a", "bc"]);
+        let res = encode(&response, "", "", "string[]");
+        let expected: Vec<U256> = vec![
+            U256::from(0x20),
+            U256::from(2),
+            U256::from(0x40),
+            U256::from(0x80),
+            U256::from(1),
+            hex!("6100000000000000000000000000000000000000000000000000000000000000").into(),
+            U256::from(2),
+            hex!("6263000000000000000000000000000000000000000000000000000000000000").into(),
+        ];
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn it_encodes_multiple_values() {
+        let response = json!({
+            "price": "12.5",
+            "wallet": "0x4128922394c63a204dd98ea6fbd887780b78bb7d",
+            "data": "0x123abc",
+        });
+        let res = encode(
+            &response,
+            "price,wallet,data,",
+            "10,,,",
+            "int256,address,bytes,timestamp",
+        );
+        let expected: Vec<U256> = vec![
+            U256::from(125),
+            hex!("0000000000000000000000004128922394c63a204dd98ea6fbd887780b78bb7d").into(),
+            U256::from(0x80),
+            U256::from(1600000000),
+            U256::from(3),
+            hex!("123abc0000000000000000000000000000000000000000000000000000000000").into(),
+        ];
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn it_checks_number_of_values() {
+        let res = params("a,b", "", "int256").encode_response(&json!({}), 0);
+        assert_eq!(res, Err(ResponseError::MismatchedCount));
+    }
+
+    #[test]
+    fn it_decodes_with_times() {
+        let response = json!({"price": 3012.55});
+        let res = encode(&response, "price", "100", "int256");
+        let values = params("", "100", "int256").decode_response(&res).unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].to_string(), "3012.55");
+    }
+
+    #[test]
+    fn it_decodes_multiple_values() {
+        let response = json!({
+            "price": "-12.5",
+            "wallet": "0x4128922394c63a204dd98ea6fbd887780b78bb7d",
+            "data": "0x123abc",
+            "name": "ETH",
+        });
+        let res = encode(
+            &response,
+            "price,wallet,data,name,",
+            "10,,,,",
+            "int256,address,bytes,string32,timestamp",
+        );
+        let values = params("", "10,,,,", "int256,address,bytes,string32,timestamp")
+            .decode_response(&res)
+            .unwrap();
+        assert_eq!(
+            values,
+            vec![
+                ResponseValue::Decimal(Decimal::from_str("-12.5").unwrap()),
+                ResponseValue::Address(hex!("4128922394c63a204dd98ea6fbd887780b78bb7d").into()),
+                ResponseValue::Bytes(vec![0x12, 0x3a, 0xbc]),
+                ResponseValue::String32("ETH".to_owned()),
+                ResponseValue::Timestamp(U256::from(1600000000)),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_decodes_arrays() {
+        let response = json!({"values": [[1.5, 2], [3, 4.75]], "names": ["a", "bc"]});
+        let res = encode(&response, "values,names", "100,", "uint256[2][],string[]");
+        let values = params("", "", "uint256[2][],string[]")
+            .decode_response(&res)
+            .unwrap();
+        assert_eq!(values[0].to_string(), "[[150, 200], [300, 475]]");
+        assert_eq!(values[1].to_string(), "[a, bc]");
+        let values = params("", "100,", "uint256[2][],string[]")
+            .decode_response(&res)
+            .unwrap();
+        assert_eq!(values[0].to_string(), "[[1.5, 2], [3, 4.75]]");
+    }
+
+    #[test]
+    fn it_reads_reserved_parameters() {
+        let abi = ABI::new(vec![
+            Param::String32 {
+                name: "_type".to_owned(),
+                value: "int256".to_owned(),
+            },
+            Param::String32 {
+                name: "_times".to_owned(),
+                value: "100".to_owned(),
+            },
+        ]);
+        let expected = ReservedParameters {
+            path: None,
+            times: Some("100".to_owned()),
+            kind: "int256".to_owned(),
+        };
+        assert_eq!(ReservedParameters::from_abi(&abi), Some(expected));
+        assert_eq!(ReservedParameters::from_abi(&ABI::none()), None);
+    }
+
+    #[test]
+    fn it_shouldnt_decode_invalid_data() {
+        let p = params("", "", "string");
+        assert_eq!(p.decode_response(&[]), Err(ResponseError::UnexpectedEnd(0)));
+        for data in [
+            vec![U256::from(0x21), U256::from(1)],
+            vec![U256::from(0x40), U256::from(1)],
+            vec![U256::from(0x20), U256::from(33), U256::zero()],
+            vec![U256::max_value()],
+        ] {
+            match p.decode_response(&data) {
+                Err(ResponseError::InvalidData { .. }) => {}
+                x => panic!("unexpected result {:?}", x),
+            }
+        }
+        let p = params("", "", "uint256[]");
+        let data = vec![U256::from(0x20), U256::from(1000)];
+        assert!(p.decode_response(&data).is_err());
+        let p = params("", "", "bool");
+        assert!(p.decode_response(&[U256::from(2)]).is_err());
+    }
+
+    #[test]
+    fn it_shouldnt_decode_overlapping_values() {
+        let response = json!({"values": [[1], [2, 3], []], "names": [["a"], ["bc", ""], []]});
+        let res = encode(&response, "values,names", ",", "uint256[][],string[][3]");
+        let values = params("", "", "uint256[][],string[][3]")
+            .decode_response(&res)
+            .unwrap();
+        assert_eq!(values[0].to_string(), "[[1], [2, 3], []]");
+        assert_eq!(values[1].to_string(), "[[a], [bc, ], []]");
+
+        // all items of the outer array refer to the same inner array
+        let p = params("", "", "uint256[][]");
+        let data: Vec<U256> = [0x20, 3, 0x60, 0x60, 0x60, 3, 1, 2, 3]
+            .iter()
+            .map(|x| U256::from(*x))
+            .collect();
+        assert_eq!(p.decode_response(&data), Err(ResponseError::Overlapping(5)));
+        // and the nesting multiplies the number of decoded words
+        let p = params("", "", "uint256[][][][][][][][]");
+        let mut data = vec![U256::from(0x20)];
+        for _ in 0..8 {
+            data.extend([U256::from(2), U256::from(0x40), U256::from(0x40)]);
+        }
+        assert!(matches!(
+            p.decode_response(&data),
+            Err(ResponseError::Overlapping(_))
+        ));
+    }
+}
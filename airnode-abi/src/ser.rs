@@ -0,0 +1,795 @@
+use crate::attr::{
+    ADDRESS_TOKEN, BYTES32_TOKEN, DATE_TOKEN, INT256_TOKEN, STRING32_TOKEN, STRING_TOKEN,
+    UINT256_TOKEN,
+};
+use crate::prelude::*;
+use crate::{Param, ABI, I256};
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+use ethereum_types::{H160, U256};
+use serde::{ser, Serialize};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum SerdeError {
+    #[error("{0}")]
+    Custom(String),
+    #[error("struct or map is expected")]
+    NotAStruct,
+    #[error("parameter name should be a string")]
+    InvalidName,
+    #[error("parameter {name}: {reason}")]
+    InvalidValue { name: String, reason: String },
+}
+
+impl ser::Error for SerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+impl serde::de::Error for SerdeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Custom(msg.to_string())
+    }
+}
+
+/// Type of the parameter, that is required by the marker of the value or by the attribute of the field
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind {
+    /// type is chosen from the serde data model
+    Auto,
+    Uint256,
+    Int256,
+    Address,
+    Bytes32,
+    String,
+    String32,
+    Date,
+    /// element of `Vec<u8>`
+    Byte,
+}
+
+impl Kind {
+    /// kind, that is required by the name of the newtype struct, if it is a marker
+    fn of_marker(name: &str) -> Option<Self> {
+        match name {
+            UINT256_TOKEN => Some(Kind::Uint256),
+            INT256_TOKEN => Some(Kind::Int256),
+            ADDRESS_TOKEN => Some(Kind::Address),
+            BYTES32_TOKEN => Some(Kind::Bytes32),
+            STRING_TOKEN => Some(Kind::String),
+            STRING32_TOKEN => Some(Kind::String32),
+            DATE_TOKEN => Some(Kind::Date),
+            _ => None,
+        }
+    }
+}
+
+/// converts structure into Airnode ABI, fields become named parameters.
+///
+/// - `bool` becomes `Bool`, signed integers and `I256` become `Int256`,
+///   unsigned integers become `Uint256`
+/// - strings and unit enum variants become `String`
+/// - `Vec<u8>` and byte buffers become `Bytes`
+/// - `None` fields are skipped
+///
+/// Other types should be forced with the helpers from `airnode_abi::attr`.
+/// `U256` and `H160` are serialized as hex strings, that can't be told from the ordinary ones,
+/// so unmarked `0x`-prefixed strings are rejected.
+pub fn to_abi<T: Serialize + ?Sized>(value: &T) -> Result<ABI, SerdeError> {
+    value.serialize(AbiSerializer)
+}
+
+/// Serializer of the top level structure into ABI
+struct AbiSerializer;
+
+/// Serializer of the structure field into the named parameter.
+/// Returns `None` for the fields that should be skipped
+struct ParamSerializer {
+    name: String,
+    kind: Kind,
+}
+
+impl ParamSerializer {
+    fn new(name: &str, kind: Kind) -> Self {
+        Self {
+            name: name.to_owned(),
+            kind,
+        }
+    }
+
+    fn invalid(&self, reason: &str) -> SerdeError {
+        SerdeError::InvalidValue {
+            name: self.name.clone(),
+            reason: reason.to_owned(),
+        }
+    }
+
+    fn unsupported(&self, kind: &str) -> SerdeError {
+        self.invalid(&format!("{} is not supported", kind))
+    }
+
+    fn uint(self, value: U256) -> Result<Option<Param>, SerdeError> {
+        let name = self.name.clone();
+        match self.kind {
+            Kind::Auto | Kind::Uint256 => Ok(Some(Param::Uint256 { name, value })),
+            Kind::Bytes32 => Ok(Some(Param::Bytes32 { name, value })),
+            Kind::Int256 => match I256::from_sign_and_abs(false, value) {
+                Ok(value) => Ok(Some(Param::Int256 { name, value })),
+                Err(e) => Err(self.invalid(&e.to_string())),
+            },
+            _ => Err(self.invalid("integer is not expected")),
+        }
+    }
+
+    fn int(self, value: I256) -> Result<Option<Param>, SerdeError> {
+        match self.kind {
+            Kind::Auto | Kind::Int256 => Ok(Some(Param::Int256 {
+                name: self.name,
+                value,
+            })),
+            _ if value.is_negative() => Err(self.invalid("negative integer is not expected")),
+            _ => {
+                let abs = value.unsigned_abs();
+                self.uint(abs)
+            }
+        }
+    }
+}
+
+impl ser::Serializer for AbiSerializer {
+    type Ok = ABI;
+    type Error = SerdeError;
+    type SerializeSeq = ser::Impossible<ABI, SerdeError>;
+    type SerializeTuple = ser::Impossible<ABI, SerdeError>;
+    type SerializeTupleStruct = ser::Impossible<ABI, SerdeError>;
+    type SerializeTupleVariant = ser::Impossible<ABI, SerdeError>;
+    type SerializeMap = ParamsSerializer;
+    type SerializeStruct = ParamsSerializer;
+    type SerializeStructVariant = ser::Impossible<ABI, SerdeError>;
+
+    fn serialize_bool(self, _: bool) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_i8(self, _: i8) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_i16(self, _: i16) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_i32(self, _: i32) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_i64(self, _: i64) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_u8(self, _: u8) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_u16(self, _: u16) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_u32(self, _: u32) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_u64(self, _: u64) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_f32(self, _: f32) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_f64(self, _: f64) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_char(self, _: char) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_str(self, _: &str) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_bytes(self, _: &[u8]) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_none(self) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<ABI, SerdeError> {
+        value.serialize(self)
+    }
+    /// unit structure has no fields
+    fn serialize_unit(self) -> Result<ABI, SerdeError> {
+        Ok(ABI::none())
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<ABI, SerdeError> {
+        Ok(ABI::none())
+    }
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<ABI, SerdeError> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<ABI, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, SerdeError> {
+        Ok(ParamsSerializer::default())
+    }
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, SerdeError> {
+        Ok(ParamsSerializer::default())
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SerdeError> {
+        Err(SerdeError::NotAStruct)
+    }
+}
+
+/// Collects the fields of the structure (or the entries of the map) as parameters
+#[derive(Default)]
+struct ParamsSerializer {
+    params: Vec<Param>,
+    /// key of the map entry, which value is not serialized yet
+    key: Option<String>,
+}
+
+impl ParamsSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<(), SerdeError> {
+        if let Some(param) = value.serialize(ParamSerializer::new(name, Kind::Auto))? {
+            self.params.push(param);
+        }
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for ParamsSerializer {
+    type Ok = ABI;
+    type Error = SerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerdeError> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<ABI, SerdeError> {
+        Ok(ABI::new(self.params))
+    }
+}
+
+impl ser::SerializeMap for ParamsSerializer {
+    type Ok = ABI;
+    type Error = SerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerdeError> {
+        match key.serialize(ParamSerializer::new("", Kind::Auto))? {
+            Some(Param::String { value, .. }) => {
+                self.key = Some(value);
+                Ok(())
+            }
+            _ => Err(SerdeError::InvalidName),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        let key = self.key.take().ok_or(SerdeError::InvalidName)?;
+        self.push(&key, value)
+    }
+
+    fn end(self) -> Result<ABI, SerdeError> {
+        Ok(ABI::new(self.params))
+    }
+}
+
+impl ser::Serializer for ParamSerializer {
+    type Ok = Option<Param>;
+    type Error = SerdeError;
+    type SerializeSeq = BytesSerializer;
+    type SerializeTuple = DateSerializer;
+    type SerializeTupleStruct = ser::Impossible<Option<Param>, SerdeError>;
+    type SerializeTupleVariant = ser::Impossible<Option<Param>, SerdeError>;
+    type SerializeMap = ser::Impossible<Option<Param>, SerdeError>;
+    type SerializeStruct = ser::Impossible<Option<Param>, SerdeError>;
+    type SerializeStructVariant = ser::Impossible<Option<Param>, SerdeError>;
+
+    fn serialize_bool(self, value: bool) -> Result<Option<Param>, SerdeError> {
+        match self.kind {
+            Kind::Auto => Ok(Some(Param::Bool {
+                name: self.name,
+                value,
+            })),
+            _ => Err(self.invalid("boolean is not expected")),
+        }
+    }
+    fn serialize_i8(self, value: i8) -> Result<Option<Param>, SerdeError> {
+        self.int(I256::from(value))
+    }
+    fn serialize_i16(self, value: i16) -> Result<Option<Param>, SerdeError> {
+        self.int(I256::from(value))
+    }
+    fn serialize_i32(self, value: i32) -> Result<Option<Param>, SerdeError> {
+        self.int(I256::from(value))
+    }
+    fn serialize_i64(self, value: i64) -> Result<Option<Param>, SerdeError> {
+        self.int(I256::from(value))
+    }
+    fn serialize_i128(self, value: i128) -> Result<Option<Param>, SerdeError> {
+        self.int(I256::from(value))
+    }
+    fn serialize_u8(self, value: u8) -> Result<Option<Param>, SerdeError> {
+        match self.kind {
+            Kind::Byte => Ok(Some(Param::Uint256 {
+                name: self.name,
+                value: U256::from(value),
+            })),
+            _ => self.uint(U256::from(value)),
+        }
+    }
+    fn serialize_u16(self, value: u16) -> Result<Option<Param>, SerdeError> {
+        self.uint(U256::from(value))
+    }
+    fn serialize_u32(self, value: u32) -> Result<Option<Param>, SerdeError> {
+        self.uint(U256::from(value))
+    }
+    fn serialize_u64(self, value: u64) -> Result<Option<Param>, SerdeError> {
+        self.uint(U256::from(value))
+    }
+    fn serialize_u128(self, value: u128) -> Result<Option<Param>, SerdeError> {
+        self.uint(U256::from(value))
+    }
+    fn serialize_f32(self, _: f32) -> Result<Option<Param>, SerdeError> {
+        Err(self.unsupported("floating point number"))
+    }
+    fn serialize_f64(self, _: f64) -> Result<Option<Param>, SerdeError> {
+        Err(self.unsupported("floating point number"))
+    }
+    fn serialize_char(self, value: char) -> Result<Option<Param>, SerdeError> {
+        self.serialize_str(&value.to_string())
+    }
+    fn serialize_str(self, value: &str) -> Result<Option<Param>, SerdeError> {
+        let name = self.name.clone();
+        let hex_digits = || value.strip_prefix("0x").unwrap_or(value);
+        match self.kind {
+            Kind::Auto if value.starts_with("0x") => Err(self.invalid(
+                "hex string is ambiguous, mark it with attr::uint256, attr::address, attr::bytes32 or attr::string",
+            )),
+            Kind::Auto | Kind::String => Ok(Some(Param::String {
+                name,
+                value: value.to_owned(),
+            })),
+            Kind::String32 => {
+                if value.len() > 32 {
+                    return Err(self.invalid("string should not exceed 32 bytes"));
+                }
+                Ok(Some(Param::String32 {
+                    name,
+                    value: value.to_owned(),
+                }))
+            }
+            Kind::Uint256 | Kind::Bytes32 => match U256::from_str(hex_digits()) {
+                Ok(x) => self.uint(x),
+                Err(e) => Err(self.invalid(&e.to_string())),
+            },
+            Kind::Address => match H160::from_str(hex_digits()) {
+                Ok(value) => Ok(Some(Param::Address { name, value })),
+                Err(e) => Err(self.invalid(&e.to_string())),
+            },
+            Kind::Int256 => match I256::from_str(value) {
+                Ok(x) => self.int(x),
+                Err(e) => Err(self.invalid(&e.to_string())),
+            },
+            Kind::Date => Err(self.invalid("tuple of year, month and day is expected")),
+            Kind::Byte => Err(self.invalid("byte is expected")),
+        }
+    }
+    fn serialize_bytes(self, value: &[u8]) -> Result<Option<Param>, SerdeError> {
+        match self.kind {
+            Kind::Auto => Ok(Some(Param::Bytes {
+                name: self.name,
+                value: value.to_vec(),
+            })),
+            Kind::Bytes32 if value.len() == 32 => Ok(Some(Param::Bytes32 {
+                name: self.name,
+                value: U256::from_big_endian(value),
+            })),
+            Kind::Bytes32 => Err(self.invalid("32 bytes are expected")),
+            _ => Err(self.invalid("bytes are not expected")),
+        }
+    }
+    fn serialize_none(self) -> Result<Option<Param>, SerdeError> {
+        Ok(None)
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Option<Param>, SerdeError> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Option<Param>, SerdeError> {
+        Ok(None)
+    }
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Option<Param>, SerdeError> {
+        Ok(None)
+    }
+    /// unit variants of the enums are stored as their names
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Option<Param>, SerdeError> {
+        self.serialize_str(variant)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Option<Param>, SerdeError> {
+        // the attribute of the field takes precedence over the marker of the value
+        let kind = match (self.kind, Kind::of_marker(name)) {
+            (Kind::Auto, Some(marker)) => marker,
+            (kind, _) => kind,
+        };
+        value.serialize(Self::new(&self.name, kind))
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Option<Param>, SerdeError> {
+        Err(self.unsupported("enum with data"))
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, SerdeError> {
+        Ok(BytesSerializer {
+            param: self,
+            bytes: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, SerdeError> {
+        if self.kind != Kind::Date {
+            return Err(self.unsupported("tuple"));
+        }
+        Ok(DateSerializer {
+            param: self,
+            parts: vec![],
+        })
+    }
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, SerdeError> {
+        Err(self.unsupported("tuple"))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerdeError> {
+        Err(self.unsupported("enum with data"))
+    }
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, SerdeError> {
+        Err(self.unsupported("map"))
+    }
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, SerdeError> {
+        Err(self.unsupported("nested structure"))
+    }
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, SerdeError> {
+        Err(self.unsupported("enum with data"))
+    }
+}
+
+/// Sequence of `u8` values, that is stored as `Bytes` parameter
+struct BytesSerializer {
+    param: ParamSerializer,
+    bytes: Vec<u8>,
+}
+
+impl ser::SerializeSeq for BytesSerializer {
+    type Ok = Option<Param>;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        match value.serialize(ParamSerializer::new(&self.param.name, Kind::Byte)) {
+            Ok(Some(Param::Uint256 { value, .. })) => self.bytes.push(value.low_u32() as u8),
+            _ => return Err(self.param.unsupported("sequence of non-u8 values")),
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Option<Param>, SerdeError> {
+        ser::Serializer::serialize_bytes(self.param, &self.bytes)
+    }
+}
+
+/// Tuple of year, month and day, that is stored as `Date` parameter
+struct DateSerializer {
+    param: ParamSerializer,
+    parts: Vec<I256>,
+}
+
+impl ser::SerializeTuple for DateSerializer {
+    type Ok = Option<Param>;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        match value.serialize(ParamSerializer::new(&self.param.name, Kind::Auto))? {
+            Some(Param::Int256 { value, .. }) => self.parts.push(value),
+            Some(Param::Uint256 { value, .. }) => match I256::from_sign_and_abs(false, value) {
+                Ok(x) => self.parts.push(x),
+                Err(e) => return Err(self.param.invalid(&e.to_string())),
+            },
+            _ => return Err(self.param.invalid("integer is expected")),
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Option<Param>, SerdeError> {
+        let parts: Vec<i64> = self
+            .parts
+            .iter()
+            .filter_map(|x| i64::try_from(*x).ok())
+            .collect();
+        match parts.as_slice() {
+            [year, month, day]
+                if *year > 0 && *year <= 9999 && (1..=12).contains(month) && *day >= 1 =>
+            {
+                Ok(Some(Param::Date {
+                    name: self.param.name,
+                    year: *year as i32,
+                    month: *month as u32,
+                    day: *day as u32,
+                }))
+            }
+            _ => Err(self.param.invalid("invalid date")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attr;
+    use hex_literal::hex;
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    #[derive(Serialize)]
+    struct Request {
+        #[serde(with = "attr::uint256")]
+        amount: U256,
+        count: u64,
+        delta: i32,
+        big: I256,
+        #[serde(with = "attr::address")]
+        wallet: H160,
+        active: bool,
+        label: String,
+        payload: Vec<u8>,
+        missing: Option<u8>,
+        #[serde(with = "attr::uint256")]
+        present: Option<U256>,
+    }
+
+    #[test]
+    fn it_serializes_struct() {
+        let wallet: H160 = hex!("4128922394c63a204dd98ea6fbd887780b78bb7d").into();
+        let res = to_abi(&Request {
+            amount: U256::from(1000),
+            count: 5,
+            delta: -3,
+            big: I256::from(-1000000),
+            wallet,
+            active: true,
+            label: "hello".to_owned(),
+            payload: vec![1, 2, 3],
+            missing: None,
+            present: Some(U256::from(7)),
+        })
+        .unwrap();
+        assert_eq!(res.schema, "1uuiiasSBu");
+        assert_eq!(
+            res.params,
+            vec![
+                Param::Uint256 {
+                    name: "amount".to_owned(),
+                    value: U256::from(1000)
+                },
+                Param::Uint256 {
+                    name: "count".to_owned(),
+                    value: U256::from(5)
+                },
+                Param::Int256 {
+                    name: "delta".to_owned(),
+                    value: I256::from(-3)
+                },
+                Param::Int256 {
+                    name: "big".to_owned(),
+                    value: I256::from(-1000000)
+                },
+                Param::Address {
+                    name: "wallet".to_owned(),
+                    value: wallet
+                },
+                Param::Bool {
+                    name: "active".to_owned(),
+                    value: true
+                },
+                Param::String {
+                    name: "label".to_owned(),
+                    value: "hello".to_owned()
+                },
+                Param::Bytes {
+                    name: "payload".to_owned(),
+                    value: vec![1, 2, 3]
+                },
+                Param::Uint256 {
+                    name: "present".to_owned(),
+                    value: U256::from(7)
+                },
+            ]
+        );
+    }
+
+    #[derive(Serialize)]
+    struct Forced {
+        #[serde(with = "attr::bytes32")]
+        id: U256,
+        #[serde(with = "attr::string32")]
+        from: String,
+        #[serde(with = "attr::date")]
+        date: (i32, u32, u32),
+    }
+
+    #[test]
+    fn it_forces_types() {
+        let res = to_abi(&Forced {
+            id: U256::from(0xabcd),
+            from: "ETH".to_owned(),
+            date: (2021, 7, 1),
+        })
+        .unwrap();
+        assert_eq!(res.schema, "1bss");
+        assert_eq!(
+            res.params[2],
+            Param::Date {
+                name: "date".to_owned(),
+                year: 2021,
+                month: 7,
+                day: 1
+            }
+        );
+        assert!(res.encode().is_ok());
+        // other serializers see the values as they are
+        let json = serde_json::to_value(&Forced {
+            id: U256::from(1),
+            from: "ETH".to_owned(),
+            date: (2021, 7, 1),
+        })
+        .unwrap();
+        assert_eq!(json["from"], "ETH");
+        assert_eq!(json["date"][0], 2021);
+    }
+
+    #[test]
+    fn it_serializes_maps() {
+        let mut map = BTreeMap::new();
+        map.insert("a", 1u8);
+        map.insert("b", 2u8);
+        let res = to_abi(&map).unwrap();
+        assert_eq!(res.schema, "1uu");
+    }
+
+    #[test]
+    fn it_requires_markers_of_hex_strings() {
+        #[derive(Serialize)]
+        struct Unmarked {
+            a: Box<U256>,
+        }
+        let res = to_abi(&Unmarked {
+            a: Box::new(U256::from(1000)),
+        });
+        assert!(matches!(res, Err(SerdeError::InvalidValue { name, .. }) if name == "a"));
+
+        #[derive(Serialize)]
+        struct Marked {
+            #[serde(with = "attr::uint256")]
+            a: Box<U256>,
+            #[serde(with = "attr::address")]
+            b: Option<H160>,
+            #[serde(with = "attr::string")]
+            c: String,
+            #[serde(with = "attr::int256")]
+            d: i8,
+        }
+        let res = to_abi(&Marked {
+            a: Box::new(U256::from(1000)),
+            b: Some(H160::zero()),
+            c: "0x3e8".to_owned(),
+            d: -1,
+        })
+        .unwrap();
+        assert_eq!(res.schema, "1uaSi");
+        assert_eq!(res.get_u256("a"), Ok(U256::from(1000)));
+        assert_eq!(res.get_string("c"), Ok("0x3e8"));
+        // marker of I256 is transparent for other serializers
+        assert_eq!(serde_json::to_value(I256::from(-5)).unwrap(), "-5");
+    }
+
+    #[test]
+    fn it_rejects_invalid_values() {
+        assert_eq!(to_abi(&5u8), Err(SerdeError::NotAStruct));
+        let mut map = BTreeMap::new();
+        map.insert("price", 1.5f64);
+        assert!(matches!(to_abi(&map), Err(SerdeError::InvalidValue { .. })));
+        #[derive(Serialize)]
+        struct TooLong {
+            #[serde(with = "attr::string32")]
+            name: String,
+        }
+        let res = to_abi(&TooLong {
+            name: "0123456789012345678901234567890123".to_owned(),
+        });
+        assert!(matches!(res, Err(SerdeError::InvalidValue { .. })));
+    }
+}
@@ -0,0 +1,138 @@
+//! JavaScript bindings, that are built with `wasm` feature.
+//!
+//! ```js
+//! import init, { encode, decode } from "./pkg/airnode_abi.js";
+//!
+//! await init();
+//! const hex = encode('[{"type":"string32","name":"from","value":"ETH"}]');
+//! const params = decode(hex, { strict: true });
+//! ```
+use crate::prelude::*;
+use crate::{DecodeOptions, ABI};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Options of decoding, as they are passed from JavaScript
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Options {
+    /// do not decode `Bytes32` and `String32` into extended types
+    strict: bool,
+    /// decode `B` schema character as `String32`
+    legacy: bool,
+    /// names of the interpreters, in the order they are tried. Overrides `strict`
+    interpreters: Option<Vec<String>>,
+}
+
+impl Options {
+    fn decode_options(&self) -> Result<DecodeOptions, String> {
+        let options = match &self.interpreters {
+            Some(list) => {
+                let mut options = DecodeOptions::strict();
+                for name in list {
+                    options = match options.with_builtin(name) {
+                        Some(x) => x,
+                        None => return Err(format!("unknown interpreter {}", name)),
+                    };
+                }
+                options
+            }
+            None if self.strict => DecodeOptions::strict(),
+            None => DecodeOptions::default(),
+        };
+        Ok(options.legacy(self.legacy))
+    }
+}
+
+/// validates and encodes parameters in the JSON format of Airnode tooling
+fn encode_json(json: &str) -> Result<String, String> {
+    let abi = ABI::from_json_str(json).map_err(|e| e.to_string())?;
+    abi.validate().map_err(|e| e.to_string())?;
+    abi.to_hex().map_err(|e| e.to_string())
+}
+
+/// decodes hex string into parameters in the JSON format of Airnode tooling
+fn decode_hex(hex: &str, opts: &Options) -> Result<Value, String> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    let bytes = hex::decode(digits).map_err(|e| e.to_string())?;
+    if bytes.len() % 32 != 0 {
+        return Err(format!(
+            "input size {} is not a multiple of 32 bytes",
+            bytes.len()
+        ));
+    }
+    let words: Vec<_> = bytes
+        .chunks(32)
+        .map(ethereum_types::U256::from_big_endian)
+        .collect();
+    let abi =
+        ABI::decode_with_options(&words, &opts.decode_options()?).map_err(|e| e.to_string())?;
+    Ok(abi.to_json())
+}
+
+/// Encodes parameters in the JSON format of Airnode tooling,
+/// `[{"type":"string32","name":"from","value":"ETH"}]`, into 0x-prefixed hex string.
+/// Throws an error with all violations, if the parameters could not be encoded
+#[wasm_bindgen]
+pub fn encode(json: &str) -> Result<String, JsValue> {
+    encode_json(json).map_err(|e| JsValue::from_str(&e))
+}
+
+/// Decodes 0x-prefixed hex string into the list of parameters in the JSON format of Airnode tooling.
+/// `opts` is an optional object `{ strict, legacy, interpreters }`,
+/// where `interpreters` is the list of names: bool, date, datetime, address, number, string32
+#[wasm_bindgen]
+pub fn decode(hex: &str, opts: JsValue) -> Result<JsValue, JsValue> {
+    let opts: Options = if opts.is_undefined() || opts.is_null() {
+        Options::default()
+    } else {
+        serde_wasm_bindgen::from_value(opts).map_err(JsValue::from)?
+    };
+    let value = decode_hex(hex, &opts).map_err(|e| JsValue::from_str(&e))?;
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(JsValue::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_encodes_decodes_json() {
+        let params = json!([
+            {"type": "string32", "name": "from", "value": "ETH"},
+            {"type": "bytes32", "name": "key", "value": "0x7472756500000000000000000000000000000000000000000000000000000000"},
+        ]);
+        let hex = encode_json(&params.to_string()).unwrap();
+        let opts = Options {
+            strict: true,
+            ..Options::default()
+        };
+        assert_eq!(decode_hex(&hex, &opts).unwrap(), params);
+        let res = decode_hex(&hex, &Options::default()).unwrap();
+        assert_eq!(res[1]["value"], "true");
+    }
+
+    #[test]
+    fn it_reports_errors() {
+        let params = json!([
+            {"type": "uint256", "name": "a", "value": "1"},
+            {"type": "uint256", "name": "a", "value": "2"},
+        ]);
+        let err = encode_json(&params.to_string()).unwrap_err();
+        assert_eq!(err, "parameter 1: duplicate name a");
+        let opts = Options {
+            interpreters: Some(vec!["ticker".to_owned()]),
+            ..Options::default()
+        };
+        let hex = encode_json("[]").unwrap();
+        assert_eq!(
+            decode_hex(&hex, &opts).unwrap_err(),
+            "unknown interpreter ticker"
+        );
+        assert!(decode_hex("0x1234", &Options::default()).is_err());
+    }
+}
@@ -1,11 +1,11 @@
 use crate::AirnodeEvent;
 use airnode_abi::{Param, ABI};
-use web3::types::H160;
+use ethereum_types::H160;
 
 fn abi_addresses(parameters: &Option<ABI>) -> Vec<H160> {
     let mut out = vec![];
     if let Some(abi) = parameters {
-        for p in &abi.params {
+        for p in abi.params() {
             if let Param::Address { value, .. } = p {
                 out.push(value.clone());
             }
@@ -97,6 +97,7 @@ pub fn get_addresses(evt: &AirnodeEvent) -> Vec<H160> {
         }
         AirnodeEvent::FailedRequest { airnode, .. } => vec![airnode.clone()],
         AirnodeEvent::FulfilledRequest { airnode, .. } => vec![airnode.clone()],
+        AirnodeEvent::FulfilledRequestV0 { airnode, .. } => vec![airnode.clone()],
         AirnodeEvent::FulfilledWithdrawal {
             airnode,
             sponsor,
@@ -199,6 +200,108 @@ pub fn get_addresses(evt: &AirnodeEvent) -> Vec<H160> {
             vec![user.clone(), admin.clone()]
         }
         AirnodeEvent::TransferredMetaAdminStatus { meta_admin, .. } => vec![meta_admin.clone()],
+        AirnodeEvent::SetDapiName { sender, .. } => vec![sender.clone()],
+        AirnodeEvent::UpdatedOevProxyBeaconSetWithSignedData { proxy, .. } => vec![proxy.clone()],
+        AirnodeEvent::UpdatedOevProxyBeaconWithSignedData { proxy, .. } => vec![proxy.clone()],
+        AirnodeEvent::Withdrew {
+            oev_proxy,
+            oev_beneficiary,
+            ..
+        } => vec![oev_proxy.clone(), oev_beneficiary.clone()],
+        AirnodeEvent::InitializedManager {
+            manager, sender, ..
+        } => vec![manager.clone(), sender.clone()],
+        AirnodeEvent::InitializedRole { sender, .. } => vec![sender.clone()],
+        AirnodeEvent::RoleGranted {
+            account, sender, ..
+        } => vec![account.clone(), sender.clone()],
+        AirnodeEvent::RoleRevoked {
+            account, sender, ..
+        } => vec![account.clone(), sender.clone()],
+        AirnodeEvent::ExtendedAuthorizationExpiration {
+            airnode,
+            requester,
+            sender,
+            ..
+        } => vec![airnode.clone(), requester.clone(), sender.clone()],
+        AirnodeEvent::RevokedIndefiniteAuthorizationStatus {
+            airnode,
+            requester,
+            setter,
+            sender,
+            ..
+        } => vec![
+            airnode.clone(),
+            requester.clone(),
+            setter.clone(),
+            sender.clone(),
+        ],
+        AirnodeEvent::SetAuthorizationExpiration {
+            airnode,
+            requester,
+            sender,
+            ..
+        } => vec![airnode.clone(), requester.clone(), sender.clone()],
+        AirnodeEvent::SetIndefiniteAuthorizationStatus {
+            airnode,
+            requester,
+            sender,
+            ..
+        } => vec![airnode.clone(), requester.clone(), sender.clone()],
+        AirnodeEvent::MadeRequest {
+            airnode,
+            requester,
+            sponsor,
+            parameters,
+            ..
+        } => {
+            let a = vec![airnode.clone(), requester.clone(), sponsor.clone()];
+            abi_addresses(parameters).iter().cloned().chain(a).collect()
+        }
+        AirnodeEvent::FulfilledRequestV1 { airnode, .. } => vec![airnode.clone()],
+        AirnodeEvent::FailedRequestV1 { airnode, .. } => vec![airnode.clone()],
+        AirnodeEvent::MadeRequestRelayed {
+            relayer,
+            airnode,
+            requester,
+            sponsor,
+            parameters,
+            ..
+        } => {
+            let a = vec![
+                relayer.clone(),
+                airnode.clone(),
+                requester.clone(),
+                sponsor.clone(),
+            ];
+            abi_addresses(parameters).iter().cloned().chain(a).collect()
+        }
+        AirnodeEvent::FulfilledRequestRelayed {
+            relayer, airnode, ..
+        } => vec![relayer.clone(), airnode.clone()],
+        AirnodeEvent::FailedRequestRelayed {
+            relayer, airnode, ..
+        } => vec![relayer.clone(), airnode.clone()],
+        AirnodeEvent::StoredTemplate { parameters, .. } => abi_addresses(parameters),
+        AirnodeEvent::StoredSubscription {
+            airnode,
+            parameters,
+            relayer,
+            sponsor,
+            requester,
+            ..
+        } => {
+            let a = vec![
+                airnode.clone(),
+                relayer.clone(),
+                sponsor.clone(),
+                requester.clone(),
+            ];
+            abi_addresses(parameters).iter().cloned().chain(a).collect()
+        }
+        AirnodeEvent::SetSlot {
+            airnode, sender, ..
+        } => vec![airnode.clone(), sender.clone()],
         _ => vec![],
     }
 }
@@ -0,0 +1,535 @@
+use crate::logreader::{EventParseError, LogReader};
+use crate::rawlog::RawLog;
+use crate::signature::{EventSignature, EventValues};
+use crate::AirnodeEvent;
+use airnode_abi::{DecodeOptions, DecodingError, PartialABI, ABI};
+use ethereum_types::{H256, U256};
+use phf::phf_map;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Known event: its declaration and the conversion of the decoded values into `AirnodeEvent`
+pub struct EventDef {
+    /// declaration of the event, as it is written in the contract
+    pub signature: &'static str,
+    /// builds the event from the values, that are taken in the order of the declaration
+    pub build: fn(&mut EventValues) -> Result<AirnodeEvent, EventParseError>,
+}
+
+/// declarations of the known events, that are parsed once, on the first decoded log
+fn parsed_signatures() -> &'static HashMap<&'static str, EventSignature> {
+    static PARSED: OnceLock<HashMap<&'static str, EventSignature>> = OnceLock::new();
+    PARSED.get_or_init(|| {
+        EVENTS
+            .values()
+            .chain(LAYOUTS.iter().map(|layout| &layout.def))
+            .filter_map(|def| Some((def.signature, def.signature.parse().ok()?)))
+            .collect()
+    })
+}
+
+impl EventDef {
+    /// returns parsed declaration of the event. Declarations of `EVENTS` and `LAYOUTS` are parsed only once
+    pub fn signature(&self) -> Result<Cow<'static, EventSignature>, EventParseError> {
+        if let Some(parsed) = parsed_signatures().get(self.signature) {
+            return Ok(Cow::Borrowed(parsed));
+        }
+        self.signature
+            .parse()
+            .map(Cow::Owned)
+            .map_err(|_| EventParseError::InvalidSignature(self.signature.to_owned()))
+    }
+
+    /// decodes the log of this event. Errors are given with the name of the event
+    /// and the topic of the log
+    pub fn decode<L: RawLog + ?Sized>(&self, log: &L) -> Result<AirnodeEvent, EventParseError> {
+        let signature = self.signature()?;
+        let decoded = LogReader::new(log, signature.indexed(), None)
+            .and_then(|mut r| signature.decode(&mut r))
+            .and_then(|mut values| (self.build)(&mut values));
+        decoded.map_err(|e| EventParseError::InEvent {
+            event: signature.name.clone(),
+            topic: log.topics().first().cloned().unwrap_or_default(),
+            source: Box::new(e),
+        })
+    }
+}
+
+/// Generation of the protocol, that the contract of the log belongs to.
+/// It tells apart the events, that have the same topic and layout in several generations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Protocol {
+    /// events are recognized by their topics, `FulfilledRequest` is decoded as in beta protocol
+    #[default]
+    Any,
+    /// AirnodeProtocol (v1), where `FulfilledRequest` has the timestamp instead of the status code
+    AirnodeProtocolV1,
+}
+
+/// Event, that has the same topic as the one in `EVENTS`, but another layout of the log.
+/// It is decoded instead of the event from `EVENTS`, when `matches` accepts the topics of the log
+/// and the protocol of its contract
+pub struct LayoutDef {
+    /// topic of the event in `EVENTS`, that has the same signature hash
+    pub topic: &'static str,
+    /// checks the topics of the log and the protocol of its contract
+    pub matches: fn(&[H256], Protocol) -> bool,
+    /// declaration of the event with this layout
+    pub def: EventDef,
+}
+
+/// returns the known event, that the log with these topics belongs to
+pub fn find(topics: &[H256], protocol: Protocol) -> Option<&'static EventDef> {
+    let topic = hex::encode(topics.first()?.as_bytes());
+    LAYOUTS
+        .iter()
+        .find(|layout| layout.topic == topic && (layout.matches)(topics, protocol))
+        .map(|layout| &layout.def)
+        .or_else(|| EVENTS.get(topic.as_str()))
+}
+
+/// returns whether the topic is the indexed address. Addresses are padded with zeros
+/// on the left, while bytes32 strings (like dAPI names) are padded on the right
+fn is_address(topic: &H256) -> bool {
+    topic.as_bytes()[..12].iter().all(|b| *b == 0)
+}
+
+/// decodes parameters of the request. When they are broken, the raw data is kept
+/// together with the parameters that could still be decoded
+fn decode_parameters(
+    chunks: Vec<U256>,
+) -> (
+    Option<ABI>,
+    Option<DecodingError>,
+    Option<Vec<U256>>,
+    Option<PartialABI>,
+) {
+    match ABI::decode(&chunks, false) {
+        Ok(x) => (Some(x), None, None, None),
+        Err(e) => {
+            let partial = ABI::decode_partial(&chunks, &DecodeOptions::default()).ok();
+            (None, Some(e), Some(chunks), partial)
+        }
+    }
+}
+
+/// builds the variant of `AirnodeEvent` from the fields, listed in the order of the declaration.
+/// `+ parameters` is used for the events that end with `bytes parameters` of the request
+/// and `+ parameters + { .. }` for the events that have more fields after them
+macro_rules! build {
+    ($variant:ident { $($field:ident),* $(,)? }) => {
+        |v: &mut EventValues| Ok(AirnodeEvent::$variant { $($field: v.next()?),* })
+    };
+    ($variant:ident { $($field:ident),* $(,)? } + parameters) => {
+        |v: &mut EventValues| {
+            $(let $field = v.next()?;)*
+            let (parameters, error, data, partial) = decode_parameters(v.next()?);
+            Ok(AirnodeEvent::$variant { $($field,)* parameters, error, data, partial })
+        }
+    };
+    ($variant:ident { $($field:ident),* $(,)? } + parameters + { $($rest:ident),* $(,)? }) => {
+        |v: &mut EventValues| {
+            $(let $field = v.next()?;)*
+            let (parameters, error, data, partial) = decode_parameters(v.next()?);
+            $(let $rest = v.next()?;)*
+            Ok(AirnodeEvent::$variant { $($field,)* parameters, error, data, partial, $($rest),* })
+        }
+    };
+}
+
+/// Known events by the hash of their signature (the first topic of the log)
+pub static EVENTS: phf::Map<&'static str, EventDef> = phf_map! {
+    // Pre-Alpha version
+    "8acbd28af1fec329994543393007c74ebc717caab62689ba09fbf938f015d3fc" => EventDef {
+        signature: "ClientEndorsementStatusUpdated(uint256 indexed requesterIndex, address indexed clientAddress, bool endorsementStatus)",
+        build: build!(ClientEndorsementStatusUpdatedA { requester_index, client_address, endorsement_status }),
+    },
+    "775e78a8e7375d14ad03d31edd0a27b29a055f732bca987abfe8082c16ed7e44" => EventDef {
+        signature: "ClientFullRequestCreated(bytes32 indexed providerId, bytes32 indexed requestId, uint256 noRequests, address clientAddress, bytes32 endpointId, uint256 requesterIndex, address designatedWallet, address fulfillAddress, bytes4 fulfillFunctionId, bytes parameters)",
+        build: build!(ClientFullRequestCreatedA { provider_id, request_id, no_requests, client_address, endpoint_id, requester_index, designated_wallet, fulfill_address, fulfill_function_id } + parameters),
+    },
+    "aff6f5e5548953a11cbb1cfdd76562512f969b0eba0a2163f2420630d4dda97b" => EventDef {
+        signature: "ClientRequestCreated(bytes32 indexed providerId, bytes32 indexed requestId, uint256 noRequests, address clientAddress, bytes32 templateId, uint256 requesterIndex, address designatedWallet, address fulfillAddress, bytes4 fulfillFunctionId, bytes parameters)",
+        build: build!(ClientRequestCreatedA { provider_id, request_id, no_requests, client_address, template_id, requester_index, designated_wallet, fulfill_address, fulfill_function_id } + parameters),
+    },
+    "1cfdd5ace64f15111ef8ed9df04364d0e9a9165cccf8386109347e54661ba3ad" => EventDef {
+        signature: "ClientRequestFailed(bytes32 indexed providerId, bytes32 indexed requestId)",
+        build: build!(ClientRequestFailedA { provider_id, request_id }),
+    },
+    "1bdbe9e5d42a025a741fc3582eb3cad4ef61ac742d83cc87e545fbd481b926b5" => EventDef {
+        signature: "ClientRequestFulfilled(bytes32 indexed providerId, bytes32 indexed requestId, uint256 statusCode, bytes32 data)",
+        build: build!(ClientRequestFulfilledA { provider_id, request_id, status_code, data }),
+    },
+    "0ebeb9b9b5c4baf915e7541c7e0919dd1a58eb06ee596035a50d08d20b9219de" => EventDef {
+        signature: "ClientRequestFulfilledWithBytes(bytes32 indexed providerId, bytes32 indexed requestId, uint256 statusCode, bytes data)",
+        build: build!(ClientRequestFulfilledWithBytesA { provider_id, request_id, status_code, data }),
+    },
+    "fcbcd5adb2d26ecd4ad50e6267e977fd479fcd0a6c82bde8eea85290ab3b46e6" => EventDef {
+        signature: "ClientShortRequestCreated(bytes32 indexed providerId, bytes32 indexed requestId, uint256 noRequests, address clientAddress, bytes32 templateId, bytes parameters)",
+        build: build!(ClientShortRequestCreatedA { provider_id, request_id, no_requests, client_address, template_id } + parameters),
+    },
+    "e5687475d94be4622dec0d6fa4db8686e003947facd485b0f4685954b8e93aa8" => EventDef {
+        signature: "EndpointUpdated(bytes32 indexed providerId, bytes32 indexed endpointId, address[] authorizers)",
+        build: build!(EndpointUpdatedA { provider_id, endpoint_id, authorizers }),
+    },
+    "40857340078796a2b6bca551f97b62fffe6ae69e2131195d461862224ee871b6" => EventDef {
+        signature: "MinBalanceUpdated(bytes32 indexed providerId, uint256 minBalance)",
+        build: build!(MinBalanceUpdatedA { provider_id, min_balance }),
+    },
+    "36ef18ad81b13124b66c80d27059d75bfadf09474c46aee8bb4ae998a921196d" => EventDef {
+        signature: "ProviderCreated(bytes32 indexed providerId, address admin, string xpub)",
+        build: build!(ProviderCreatedA { provider_id, admin, xpub }),
+    },
+    "b7de80d002230ae37dd9e25804e78c41517296ad969a962ef5457be94cb8ac6e" => EventDef {
+        signature: "ProviderUpdated(bytes32 indexed providerId, address admin)",
+        build: build!(ProviderUpdatedA { provider_id, admin }),
+    },
+    "59e98f4c18a6c92efe8c23bcbd74f0d71e271eebf9a95f9edefdbee17c01f270" => EventDef {
+        signature: "RequesterCreated(uint256 indexed requesterIndex, address admin)",
+        build: build!(RequesterCreatedA { requester_index, admin }),
+    },
+    "de26d3d8fc98a8dab0df21ef2146d313da1a060d635f3ce9b42adab32fa992aa" => EventDef {
+        signature: "RequesterUpdated(uint256 indexed requesterIndex, address admin)",
+        build: build!(RequesterUpdatedA { requester_index, admin }),
+    },
+    "2cb6f3105333165ac08235b122e2651dae9c2e70787572aa65bde31fe838d90d" => EventDef {
+        signature: "RequestFulfilled(bytes32 requestId, uint256 statusCode, bytes32 data)",
+        build: build!(RequestFulfilledA { request_id, status_code, data }),
+    },
+    "13873a3c5277d69c913bb408d87512468d41afb41113dd46eee917ec4eceb04b" => EventDef {
+        signature: "RequestFulfilledWithBytes(bytes32 requestId, uint256 statusCode, bytes data)",
+        build: build!(RequestFulfilledWithBytesA { request_id, status_code, data }),
+    },
+    "fa33b8597a1a83305d334562a90f8b4ce657e1b33c081423b6a44792d1cf41a4" => EventDef {
+        signature: "TemplateCreated(bytes32 indexed templateId, bytes32 providerId, bytes32 endpointId, uint256 requesterIndex, address designatedWallet, address fulfillAddress, bytes4 fulfillFunctionId, bytes parameters)",
+        build: build!(TemplateCreatedA { template_id, provider_id, endpoint_id, requester_index, designated_wallet, fulfill_address, fulfill_function_id } + parameters),
+    },
+    "9e7b58b29aa3b972bb0f457499d0dfd00bf23905b0c3358fb864e7120402aefa" => EventDef {
+        signature: "WithdrawalFulfilled(bytes32 indexed providerId, uint256 indexed requesterIndex, bytes32 indexed withdrawalRequestId, address designatedWallet, address destination, uint256 amount)",
+        build: build!(WithdrawalFulfilledA { provider_id, requester_index, withdrawal_request_id, designated_wallet, destination, amount }),
+    },
+    "3d0ebccb4fc9730699221da0180970852f595ed5c78781346149123cbbe9f1d3" => EventDef {
+        signature: "WithdrawalRequested(bytes32 indexed providerId, uint256 indexed requesterIndex, bytes32 indexed withdrawalRequestId, address designatedWallet, address destination)",
+        build: build!(WithdrawalRequestedA { provider_id, requester_index, withdrawal_request_id, designated_wallet, destination }),
+    },
+
+    // Beta protocol version
+    // the topic was observed in the logs of the beta contracts, but it is not the hash of
+    // the declaration below. The layout of the data matches it though
+    "dfa496c578099ee263f6fbdc842c01815924953f92c186099d640f910c1f92de" => EventDef {
+        signature: "CreatedTemplate(bytes32 indexed templateId, address airnode, bytes32 endpointId, bytes parameters)",
+        build: build!(CreatedTemplate { template_id, airnode, endpoint_id } + parameters),
+    },
+    "b4a13e8a5b83b6572fd11170aa28965f4b16ce6ed228501322a428b48e34230c" => EventDef {
+        signature: "DecreasedSelfRank(address indexed admin, uint256 newRank)",
+        build: build!(DecreasedSelfRank { admin, new_rank }),
+    },
+    "907b7436750d9bb04b635c837b151be449230b1975dac4ba31b01343b41eb75c" => EventDef {
+        signature: "DecreasedSelfRank(address indexed adminned, address indexed admin, uint256 newRank)",
+        build: build!(DecreasedSelfRankAdminned { adminned, admin, new_rank }),
+    },
+    "df7c6cf6c7d32bf473537bcf24259094d6e7cb863700e071f65a4d8a05b6ce5e" => EventDef {
+        signature: "ErroredBeaconUpdate(bytes32 indexed templateId, bytes32 requestId, uint256 statusCode)",
+        build: build!(ErroredBeaconUpdate { template_id, request_id, status_code }),
+    },
+    "f9b174be67f83278d4516865d1b9ba4576b73e523ea0c2f124ea29152bb1b676" => EventDef {
+        signature: "ExtendedWhitelistExpiration(address indexed airnode, bytes32 endpointId, address indexed user, address indexed admin, uint256 expiration)",
+        build: build!(ExtendedWhitelistExpiration { airnode, endpoint_id, user, admin, expiration }),
+    },
+    "a9e0c89b898eb7a904617915dc5b5510d539c899810042e9248569b54b9cc2ed" => EventDef {
+        signature: "ExtendedWhitelistExpiration(bytes32 indexed templateId, address indexed user, address indexed admin, uint256 expiration)",
+        build: build!(ExtendedWhitelistExpirationTpl { template_id, user, admin, expiration }),
+    },
+    "8c087e42b178608800a2ea8b3d009bdbbf75e0d23426510c2edd447d4f8b8ebd" => EventDef {
+        signature: "FailedRequest(address indexed airnode, bytes32 indexed requestId)",
+        build: |v: &mut EventValues| Ok(AirnodeEvent::FailedRequest {
+            airnode: v.next()?,
+            request_id: v.next()?,
+            error_message: String::new(),
+        }),
+    },
+    "c7143b2270cddda57e0087ca5e2a4325657dcab10d10f6b1f9d5ce6b41cb97fc" => EventDef {
+        signature: "FailedRequest(address indexed airnode, bytes32 indexed requestId, string errorMessage)",
+        build: build!(FailedRequest { airnode, request_id, error_message }),
+    },
+    "d1cc11d12363af4b6022e66d14b18ba1779ecd85a5b41891349d530fb6eee066" => EventDef {
+        signature: "FulfilledRequest(address indexed airnode, bytes32 indexed requestId, uint256 statusCode, bytes data)",
+        build: build!(FulfilledRequest { airnode, request_id, status_code, data }),
+    },
+    "adb4840bbd5f924665ae7e0e0c83de5c0fb40a98c9b57dba53a6c978127a622e" => EventDef {
+        signature: "FulfilledWithdrawal(address indexed airnode, address indexed sponsor, bytes32 indexed withdrawalRequestId, address sponsorWallet, uint256 amount)",
+        build: build!(FulfilledWithdrawal { airnode, sponsor, withdrawal_request_id, sponsor_wallet, amount }),
+    },
+    "3a52c462346de2e9436a3868970892956828a11b9c43da1ed43740b12e1125ae" => EventDef {
+        signature: "MadeFullRequest(address indexed airnode, bytes32 indexed requestId, uint256 requesterRequestCount, uint256 chainId, address requester, bytes32 endpointId, address sponsor, address sponsorWallet, address fulfillAddress, bytes4 fulfillFunctionId, bytes parameters)",
+        build: build!(MadeFullRequest { airnode, request_id, requester_request_count, chain_id, requester, endpoint_id, sponsor, sponsor_wallet, fulfill_address, fulfill_function_id } + parameters),
+    },
+    "eb39930cdcbb560e6422558a2468b93a215af60063622e63cbb165eba14c3203" => EventDef {
+        signature: "MadeTemplateRequest(address indexed airnode, bytes32 indexed requestId, uint256 requesterRequestCount, uint256 chainId, address requester, bytes32 templateId, address sponsor, address sponsorWallet, address fulfillAddress, bytes4 fulfillFunctionId, bytes parameters)",
+        build: build!(MadeTemplateRequest { airnode, request_id, requester_request_count, chain_id, requester, template_id, sponsor, sponsor_wallet, fulfill_address, fulfill_function_id } + parameters),
+    },
+    "db6e5ad2f932677d9abcb868239c24d484d5512caf71029b8b7c2309aeee760a" => EventDef {
+        signature: "RequestedBeaconUpdate(bytes32 indexed templateId, address indexed sponsor, address indexed requester, bytes32 requestId, address sponsorWallet)",
+        build: build!(RequestedBeaconUpdate { template_id, sponsor, requester, request_id, sponsor_wallet }),
+    },
+    "d48d52c7c6d0c940f3f8d07591e1800ef3a70daf79929a97ccd80b4494769fc7" => EventDef {
+        signature: "RequestedWithdrawal(address indexed airnode, address indexed sponsor, bytes32 indexed withdrawalRequestId, address sponsorWallet)",
+        build: build!(RequestedWithdrawal { airnode, sponsor, withdrawal_request_id, sponsor_wallet }),
+    },
+    "ebace4380f1ba3ccf701db78879a937b0ad2a9370e98baaba922228f632383e0" => EventDef {
+        signature: "SetAirnodeXpub(address indexed airnode, string xpub)",
+        build: build!(SetAirnodeXpub { airnode, xpub }),
+    },
+    "584a7e3e68feb90397faadcb0af28a855e0268ddedf9fce510b4cf57770b9410" => EventDef {
+        signature: "SetRank(address indexed adminned, address indexed callerAdmin, address indexed targetAdmin, uint256 newRank)",
+        build: build!(SetRankAdminned { adminned, caller_admin, target_admin, new_rank }),
+    },
+    "07048cabcdd89c62fecf542621231579eae613db4aeb83794e9c3abf428840ca" => EventDef {
+        signature: "SetRank(address indexed callerAdmin, address indexed targetAdmin, uint256 newRank)",
+        build: build!(SetRank { caller_admin, target_admin, new_rank }),
+    },
+    "c2e532a12bbcce2bfa2ef9e4bee80180e4e1b1f78618f0d20bc49a648b577c56" => EventDef {
+        signature: "SetSponsorshipStatus(address indexed sponsor, address indexed requester, bool sponsorshipStatus)",
+        build: build!(SetSponsorshipStatus { sponsor, requester, sponsorship_status }),
+    },
+    "5a3b1968640fbb8b12349ea1a58be5c61eaec6e38c11c38652f1d250207103ab" => EventDef {
+        signature: "SetUpdatePermissionStatus(address indexed sponsor, address indexed updateRequester, bool status)",
+        build: build!(SetUpdatePermissionStatus { sponsor, update_requester, status }),
+    },
+    "375ee45428e158031095010484fd6451af89c501c79d75e390da4e91eb480ce1" => EventDef {
+        signature: "SetWhitelistExpiration(address indexed airnode, bytes32 endpointId, address indexed user, address indexed admin, uint256 expiration)",
+        build: build!(SetWhitelistExpiration { airnode, endpoint_id, user, admin, expiration }),
+    },
+    "d19e89b7d547ccf349211588a9a1d29461e2ce984b1b1cdbe7150976528b86f1" => EventDef {
+        signature: "SetWhitelistExpiration(bytes32 indexed templateId, address indexed user, address indexed admin, uint256 expiration)",
+        build: build!(SetWhitelistExpirationTpl { template_id, user, admin, expiration }),
+    },
+    "0e8af304f7f920661493a5051df03a3947d58b4f655581e51ab0c014d768d8eb" => EventDef {
+        signature: "SetWhitelistStatusPastExpiration(address indexed airnode, bytes32 endpointId, address indexed user, address indexed admin, bool status)",
+        build: build!(SetWhitelistStatusPastExpiration { airnode, endpoint_id, user, admin, status }),
+    },
+    "527f03e7cb13db04fc83c2332106b6087c66d253bca13289f5d91d8e73796d11" => EventDef {
+        signature: "SetWhitelistStatusPastExpiration(bytes32 indexed templateId, address indexed user, address indexed admin, bool status)",
+        build: build!(SetWhitelistStatusPastExpirationTpl { template_id, user, admin, status }),
+    },
+    "c3dafe3cca75d9d099b1941e05b199870f55b853dd49784a96359ac26f01bf6d" => EventDef {
+        signature: "TransferredMetaAdminStatus(address indexed metaAdmin)",
+        build: build!(TransferredMetaAdminStatus { meta_admin }),
+    },
+    "cb9c65e5f99c20826a331174f56e8c536161ecb0b5d598267e79c83567d477f1" => EventDef {
+        signature: "UpdatedBeacon(bytes32 indexed templateId, bytes32 requestId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeacon { template_id, request_id, value, timestamp }),
+    },
+
+    // AirnodeRrpV0, released with Airnode v0.2. Its other events are declared as in beta protocol
+    "ba204bad31c4ec4b9b54164af94ae10c4e7312f22c0f9c065d6319c928ec9e7a" => EventDef {
+        signature: "CreatedTemplate(bytes32 indexed templateId, address airnode, bytes32 endpointId, bytes parameters)",
+        build: build!(CreatedTemplate { template_id, airnode, endpoint_id } + parameters),
+    },
+    "c0977dab79883641ece94bb6a932ca83049f561ffff8d8daaeafdbc1acce9e0a" => EventDef {
+        signature: "FulfilledRequest(address indexed airnode, bytes32 indexed requestId, bytes data)",
+        build: build!(FulfilledRequestV0 { airnode, request_id, data }),
+    },
+
+    // DapiServer
+    "bf747faf738dbfb7e8b225265a7d7afd921e2ddd91b2bc5b87984d12d41c8b35" => EventDef {
+        signature: "UpdatedBeaconWithRrp(bytes32 indexed beaconId, bytes32 requestId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeaconWithRrp { beacon_id, request_id, value, timestamp }),
+    },
+    "2b85d71813342fd0091d4573a5f78e244c2133755e470943c33a6dbf3cf9c15d" => EventDef {
+        signature: "UpdatedBeaconWithPsp(bytes32 indexed beaconId, bytes32 subscriptionId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeaconWithPsp { beacon_id, subscription_id, value, timestamp }),
+    },
+    "403078446dab7471f481ca4bffac706bd84a6dba118980d267676c096f2ba924" => EventDef {
+        signature: "UpdatedBeaconWithSignedData(bytes32 indexed beaconId, int256 value, uint256 timestamp)",
+        build: build!(UpdatedBeaconWithSignedData { beacon_id, value, timestamp }),
+    },
+    "b34747dc40d9c985b4857c2955fec7a8f34d88bc06da72f43319795758800407" => EventDef {
+        signature: "UpdatedBeaconSetWithSignedData(bytes32 indexed dapiId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeaconSetWithSignedData { beacon_set_id, value, timestamp }),
+    },
+    // DapiServer and Api3ServerV1
+    "b7712be6248d021e8c56ac9613c09491354a4d0f4ad0b7db1a664b35be4b2349" => EventDef {
+        signature: "UpdatedBeaconSetWithBeacons(bytes32 indexed beaconSetId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeaconSetWithBeacons { beacon_set_id, value, timestamp }),
+    },
+    // Api3ServerV1
+    "1ffdb573afe7273932e253bc4b8a17b9da4d37d7219ba05e464358975b36efb7" => EventDef {
+        signature: "UpdatedBeaconWithSignedData(bytes32 indexed beaconId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedBeaconWithSignedData { beacon_id, value, timestamp }),
+    },
+    // DapiServer has the same topic with another layout, see `LAYOUTS`
+    "f3a9aac9b6ac0f842cb5d9b3491cd5fc1b6a6778d97fd9529f587339865294f5" => EventDef {
+        signature: "SetDapiName(bytes32 indexed dataFeedId, bytes32 indexed dapiName, address sender)",
+        build: build!(SetDapiName { data_feed_id, dapi_name, sender }),
+    },
+    "c856aaa4e639403f366ad68f07eb69bad4c044a35b0369c4ef1ea3a427a6a0ee" => EventDef {
+        signature: "UpdatedOevProxyBeaconWithSignedData(bytes32 indexed beaconId, address indexed proxy, bytes32 indexed updateId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedOevProxyBeaconWithSignedData { beacon_id, proxy, update_id, value, timestamp }),
+    },
+    "dd29860e0772a39dea2ff0520d79f8efdffe48c903d6bbbc0c2cc65dc6568a7f" => EventDef {
+        signature: "UpdatedOevProxyBeaconSetWithSignedData(bytes32 indexed beaconSetId, address indexed proxy, bytes32 indexed updateId, int224 value, uint32 timestamp)",
+        build: build!(UpdatedOevProxyBeaconSetWithSignedData { beacon_set_id, proxy, update_id, value, timestamp }),
+    },
+    "0472be967f9a37138dfea1875af44784cafb79f92044ab33d7d6958eddd9ca6c" => EventDef {
+        signature: "Withdrew(address indexed oevProxy, address oevBeneficiary, uint256 amount)",
+        build: build!(Withdrew { oev_proxy, oev_beneficiary, amount }),
+    },
+
+    // AccessControlRegistry, role events are inherited from OpenZeppelin AccessControl
+    "875abd51165f03877e956b2e4342de31979d7dd8d271176cf24151278f355a1d" => EventDef {
+        signature: "InitializedManager(bytes32 indexed rootRole, address indexed manager, address sender)",
+        build: build!(InitializedManager { root_role, manager, sender }),
+    },
+    "532ead3ec09896bef1351791fbaad86ac03f3204090a8e7f173f41414b1fdac0" => EventDef {
+        signature: "InitializedRole(bytes32 indexed role, bytes32 indexed adminRole, string description, address sender)",
+        build: build!(InitializedRole { role, admin_role, description, sender }),
+    },
+    "2f8788117e7eff1d82e926ec794901d17c78024a50270940304540a733656f0d" => EventDef {
+        signature: "RoleGranted(bytes32 indexed role, address indexed account, address indexed sender)",
+        build: build!(RoleGranted { role, account, sender }),
+    },
+    "f6391f5c32d9c69d2a47ea670b442974b53935d1edc7fd64eb21e047a839171b" => EventDef {
+        signature: "RoleRevoked(bytes32 indexed role, address indexed account, address indexed sender)",
+        build: build!(RoleRevoked { role, account, sender }),
+    },
+
+    // RequesterAuthorizerWithAirnode
+    "b43bd9cc9e739fee90fb5adb8f69a026c2d912e6cd702325b379953f5dcdd415" => EventDef {
+        signature: "ExtendedAuthorizationExpiration(address indexed airnode, bytes32 endpointId, address indexed requester, uint32 expirationTimestamp, address sender)",
+        build: build!(ExtendedAuthorizationExpiration { airnode, endpoint_id, requester, expiration_timestamp, sender }),
+    },
+    "12f5ec1bf71aa559e0c451b946620cd0b6f15bd212d23966dadc29e244b17e47" => EventDef {
+        signature: "RevokedIndefiniteAuthorizationStatus(address indexed airnode, bytes32 endpointId, address indexed requester, address indexed setter, uint224 indefiniteAuthorizationCount, address sender)",
+        build: build!(RevokedIndefiniteAuthorizationStatus { airnode, endpoint_id, requester, setter, indefinite_authorization_count, sender }),
+    },
+    "f6ac5536e6dc6c3d698a8db4fe44b64ca03bf017e570a8435e45188274b78fce" => EventDef {
+        signature: "SetAuthorizationExpiration(address indexed airnode, bytes32 endpointId, address indexed requester, uint32 expirationTimestamp, address sender)",
+        build: build!(SetAuthorizationExpiration { airnode, endpoint_id, requester, expiration_timestamp, sender }),
+    },
+    "d21ef0607d3a21c87c246826c1e780dbcffec8e8b09ac7518578feed938c8354" => EventDef {
+        signature: "SetIndefiniteAuthorizationStatus(address indexed airnode, bytes32 endpointId, address indexed requester, bool status, uint224 indefiniteAuthorizationCount, address sender)",
+        build: build!(SetIndefiniteAuthorizationStatus { airnode, endpoint_id, requester, status, indefinite_authorization_count, sender }),
+    },
+
+    // AirnodeProtocol (v1), with requests relayed on behalf of airnodes
+    "6537a04eb75e255385ee16033b5cb61105ea1b3ab614f6455fcad6ee88a60173" => EventDef {
+        signature: "MadeRequest(address indexed airnode, bytes32 indexed requestId, address requester, uint256 requesterRequestCount, bytes32 templateId, bytes parameters, address sponsor, bytes4 fulfillFunctionId)",
+        build: build!(MadeRequest { airnode, request_id, requester, requester_request_count, template_id } + parameters + { sponsor, fulfill_function_id }),
+    },
+    // FulfilledRequest has the same topic and layout as in beta protocol, see `LAYOUTS`
+    "aee73eafb4f543f82048dfc7d8c9a4ee62506ffa76e0051b749d102c4ac7924c" => EventDef {
+        signature: "FailedRequest(address indexed airnode, bytes32 indexed requestId, uint256 timestamp, string errorMessage)",
+        build: build!(FailedRequestV1 { airnode, request_id, timestamp, error_message }),
+    },
+    "b1fea87872f337aefbe87f34814e8769f6f84c265bf74098ac9122c4b6268cbc" => EventDef {
+        signature: "MadeRequestRelayed(address indexed relayer, bytes32 indexed requestId, address indexed airnode, address requester, uint256 requesterRequestCount, bytes32 templateId, bytes parameters, address sponsor, bytes4 fulfillFunctionId)",
+        build: build!(MadeRequestRelayed { relayer, request_id, airnode, requester, requester_request_count, template_id } + parameters + { sponsor, fulfill_function_id }),
+    },
+    "bc2b56fbde291a214ed04ac9fd94c2e53c29b63d1cbdacedfe922d7523cf69eb" => EventDef {
+        signature: "FulfilledRequestRelayed(address indexed relayer, bytes32 indexed requestId, address indexed airnode, uint256 timestamp, bytes data)",
+        build: build!(FulfilledRequestRelayed { relayer, request_id, airnode, timestamp, data }),
+    },
+    "5bfd527f98a7329226e18789dcd6caead784424ffb409bae4584f8e88ee39849" => EventDef {
+        signature: "FailedRequestRelayed(address indexed relayer, bytes32 indexed requestId, address indexed airnode, uint256 timestamp, string errorMessage)",
+        build: build!(FailedRequestRelayed { relayer, request_id, airnode, timestamp, error_message }),
+    },
+    "40743c23e28a8b92f5219dee916cac13f53334209cee6dd9b7fd365e6607c8e7" => EventDef {
+        signature: "StoredTemplate(bytes32 indexed templateId, bytes32 endpointId, bytes parameters)",
+        build: build!(StoredTemplate { template_id, endpoint_id } + parameters),
+    },
+    "544f5b2023290d39a7c3271c6ff6f3f0720e696fa4459d41c949cc02a98314ba" => EventDef {
+        signature: "StoredSubscription(bytes32 indexed subscriptionId, uint256 chainId, address airnode, bytes32 templateId, bytes parameters, bytes conditions, address relayer, address sponsor, address requester, bytes4 fulfillFunctionId)",
+        build: build!(StoredSubscription { subscription_id, chain_id, airnode, template_id } + parameters + { conditions, relayer, sponsor, requester, fulfill_function_id }),
+    },
+    // AllocatorWithAirnode and AllocatorWithManager
+    "e19287a9ede6ea7b5d5e69cdc27bd6f28d07a7a4d9b2ae41f9b8bd2d97bfcf6e" => EventDef {
+        signature: "SetSlot(address indexed airnode, uint256 indexed slotIndex, bytes32 subscriptionId, uint32 expirationTimestamp, address sender)",
+        build: build!(SetSlot { airnode, slot_index, subscription_id, expiration_timestamp, sender }),
+    },
+
+    // Topics from other versions/branches (probably abandoned or deprecated):
+    // "cee45ff00381d88710040af9bd1c13ee3176f83efd36b33f2330cca74e53000b" => "AirnodeParametersSet(bytes32,address,string,address[])",
+    // "450d1b4c87d285de4ba1f262cca585d6bb3184ca909e14263ac31a044682d429" => "CheckAuthorizationStatus(bytes32,bytes32,bytes32,uint256,address,address)",
+    // "c39b3f29cca3bbf148a7d996d34223a627c7df660f04f3d28e915ae11eea446c" => "CheckAuthorizationStatuses(bytes32,bytes32[],bytes32[],uint256[],address[],address[])",
+    // "d859fb74ec5a83da09d56bb4211667f87fc36cdec8ccdd9ddc7115c99923e75e" => "ClientEndorsementStatusSet(uint256,address,bool)",
+    // "e8ae99161b1547fd1c6ff3cb9660293fa4cd770fd52f72ff0362d64d8bccc08e" => "ClientFullRequestCreated(bytes32,bytes32,uint256,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
+    // "8339fddbb81e588a9ed04dec82ee9ae6c7a185f44835adaaa2ace50ce3a14aaf" => "ClientRequestCreated(bytes32,bytes32,uint256,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
+    // "cde46e28d8d3e348e5f5b4fcc511fe3b1f9b0f549cd8332f0da31802a6f2bf61" => "ClientRequestFulfilled(bytes32,bytes32,uint256,bytes)",
+    // "14c9c5c9712ca2ec34c62aaeb946d2762f472ad43612ad1a7fb549e274715e39" => "CreateRequester(address)",
+    // "57fd46b3414df2b35458ac6ab35236164c21fab7f784d250882d66a197e8cdaf" => "CreateTemplate(bytes32,bytes32,bytes)",
+    // "ddac55da39d52cd1fe3f9a6832cb6fa4c1fb45d755c47f984cc5a1c48aa774cf" => "DecreasedSelfRank(bytes32,address,uint256)",
+    // "98f45a41d9f64ecabfb00dc5bc4f6aea7e50c2afb9eebee8936be5dbcad03dc2" => "ExtendedWhitelistExpiration(bytes32,address,uint256,address)",
+    // "3338d71fe7787f614d15e7da994eaa07eb176c0e5193ba05cc0b71cc10017665" => "Fail(bytes32,bytes32,address,bytes4)",
+    // "a3ecafb4214d6c3c8dbf95d5fe8e92051c9ac156e0584a00b2a7e16b2df48c0a" => "Fulfill(bytes32,bytes32,uint256,bytes,address,bytes4)",
+    // "8ff67212697f6648c4c6355eb59d2e859c8b0de2e42e5c226e43626ec8c38355" => "FulfillWithdrawal(bytes32,bytes32,uint256,address)",
+    // "cf16e219d0e9946ba140541a449711b0d9e7e3600c4fd63f3c5c1e6f7e78f789" => "MakeFullRequest(bytes32,bytes32,uint256,address,address,bytes4,bytes)",
+    // "bc7e6375ca8aaa60eba7a35af9f5692d6de892654b6d794613be986377467d90" => "MakeRequest(bytes32,uint256,address,address,bytes4,bytes)",
+    // "19233d41da09723f3b102e7a0e192a478c3cd72aa3269f529824b8cc632043a3" => "RequestWithdrawal(bytes32,uint256,address,address)",
+    // "430ace8db43187f56ab9e66a2421b7044fa806a347e0a402a646dc9d0edb0cb1" => "SetApi3Admin(address)",
+    // "aeae98ebd6f5c18f7e64fdd4102cb25feb3552afa6f81285fb4f942e2e41fc3f" => "SetAirnodeParameters(address,string,address[])",
+    // "5a0aa1abecb91b072383fcd5858f29ed6135a87dd2f2ab02e8f77def11f19bc2" => "SetAirnodeParametersAndForwardFunds(address,string,address[])",
+    // "5a570f3df76e0cf7e768a1227f789390627bb125bd73a160596a2559aafdc2da" => "SetClientEndorsementStatus(uint256,address,bool)",
+    // "19b9e44e700d40233866bbf1eaa4d9465d357e53969945a144ef247f39a0836a" => "SetRank(bytes32,address,uint256,address)",
+    // "c980cf6eca9a252c38dc7738fb47cd57dfbf37bda6664adfbab67a09df1af859" => "SetRequesterAdmin(uint256,address)",
+    // "0c4d21ef7140ba56cb38499615ee0b0d3258917f74c778590e597a47ad529ac1" => "SetWhitelistExpiration(bytes32,address,uint256,address)",
+    // "12abbeaa6fd4b14c4a9208f65d65c03b44c354145ba54d20b11474254438408f" => "SetWhitelistStatusPastExpiration(bytes32,address,bool,address)",
+    // "a3c44778bf2c4729d112c7eeee01a2a79be70e58e3fe0b2a25d6c3562f73ab83" => "TemplateCreated(bytes32,bytes32,bytes32,bytes)",
+};
+
+/// Events with the topics of `EVENTS`, that are told apart by their indexed values
+/// or by the protocol of the contract
+pub static LAYOUTS: &[LayoutDef] = &[
+    // DapiServer indexes the sender instead of the data feed ID of Api3ServerV1
+    LayoutDef {
+        topic: "f3a9aac9b6ac0f842cb5d9b3491cd5fc1b6a6778d97fd9529f587339865294f5",
+        matches: |topics, _| topics.get(2).is_some_and(is_address),
+        def: EventDef {
+            signature:
+                "SetDapiName(bytes32 indexed dapiName, bytes32 dataFeedId, address indexed sender)",
+            build: build!(SetDapiName {
+                dapi_name,
+                data_feed_id,
+                sender
+            }),
+        },
+    },
+    // AirnodeProtocol (v1), which logs can't be told apart from the ones of beta protocol
+    LayoutDef {
+        topic: "d1cc11d12363af4b6022e66d14b18ba1779ecd85a5b41891349d530fb6eee066",
+        matches: |_, protocol| protocol == Protocol::AirnodeProtocolV1,
+        def: EventDef {
+            signature: "FulfilledRequest(address indexed airnode, bytes32 indexed requestId, uint256 timestamp, bytes data)",
+            build: build!(FulfilledRequestV1 { airnode, request_id, timestamp, data }),
+        },
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tiny_keccak::{Hasher, Keccak};
+
+    // topics, that are not hashes of their declarations
+    const UNVERIFIED: &[&str] =
+        &["dfa496c578099ee263f6fbdc842c01815924953f92c186099d640f910c1f92de"];
+
+    #[test]
+    fn it_matches_topics_of_declarations() {
+        for (topic, def) in EVENTS.entries().filter(|(t, _)| !UNVERIFIED.contains(t)) {
+            let mut hash = [0u8; 32];
+            let mut keccak = Keccak::v256();
+            keccak.update(def.signature().unwrap().canonical().as_bytes());
+            keccak.finalize(&mut hash);
+            assert_eq!(hex::encode(hash), *topic, "{}", def.signature);
+        }
+        for layout in LAYOUTS {
+            let def = EVENTS.get(layout.topic).unwrap();
+            assert_eq!(
+                layout.def.signature().unwrap().canonical(),
+                def.signature().unwrap().canonical()
+            );
+        }
+    }
+}
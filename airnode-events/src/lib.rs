@@ -1,13 +1,19 @@
 mod addresses;
+mod events;
 pub(crate) mod logreader;
+mod rawlog;
 mod requests;
+mod signature;
 
-use crate::logreader::{EventParseError, LogReader};
-use airnode_abi::{DecodingError, ABI};
-use hex_literal::hex;
-use phf::phf_map;
+pub use crate::events::Protocol;
+pub use crate::logreader::EventParseError;
+use airnode_abi::{
+    DecodingError, PartialABI, ReservedParameters, ResponseError, ResponseValue, ABI, I256,
+};
+use ethereum_types::{H160, U256};
+pub use rawlog::{LogEntry, RawLog};
 use serde::{Deserialize, Serialize};
-use web3::types::{H160, U256};
+pub use signature::{EventParam, EventSignature, ParamKind};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type")]
@@ -33,6 +39,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     ClientRequestCreatedA {
         provider_id: U256,
@@ -50,6 +58,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     ClientRequestFailedA {
         provider_id: U256,
@@ -79,6 +89,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     EndpointUpdatedA {
         provider_id: U256,
@@ -130,6 +142,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     WithdrawalFulfilledA {
         provider_id: U256,
@@ -157,6 +171,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     DecreasedSelfRank {
         admin: H160,
@@ -196,6 +212,12 @@ pub enum AirnodeEvent {
         status_code: u64,
         data: Vec<U256>,
     },
+    // AirnodeRrpV0 reports only successful fulfillments, without status code
+    FulfilledRequestV0 {
+        airnode: H160,
+        request_id: U256,
+        data: Vec<U256>,
+    },
     FulfilledWithdrawal {
         airnode: H160,
         sponsor: H160,
@@ -220,6 +242,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     MadeTemplateRequest {
         airnode: H160,
@@ -238,6 +262,8 @@ pub enum AirnodeEvent {
         error: Option<DecodingError>,
         #[serde(skip_serializing_if = "Option::is_none")]
         data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
     },
     RequestedBeaconUpdate {
         template_id: U256,
@@ -309,8 +335,221 @@ pub enum AirnodeEvent {
     UpdatedBeacon {
         template_id: U256,
         request_id: U256,
-        value: U256,
-        timestamp: u64,
+        value: I256,
+        // unix timestamp of the value, in seconds
+        timestamp: u32,
+    },
+
+    // data feeds of DapiServer and Api3ServerV1
+    SetDapiName {
+        data_feed_id: U256,
+        dapi_name: String,
+        sender: H160,
+    },
+    UpdatedBeaconSetWithBeacons {
+        beacon_set_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedBeaconSetWithSignedData {
+        beacon_set_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedBeaconWithPsp {
+        beacon_id: U256,
+        subscription_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedBeaconWithRrp {
+        beacon_id: U256,
+        request_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedBeaconWithSignedData {
+        beacon_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedOevProxyBeaconSetWithSignedData {
+        beacon_set_id: U256,
+        proxy: H160,
+        update_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    UpdatedOevProxyBeaconWithSignedData {
+        beacon_id: U256,
+        proxy: H160,
+        update_id: U256,
+        value: I256,
+        timestamp: u32,
+    },
+    Withdrew {
+        oev_proxy: H160,
+        oev_beneficiary: H160,
+        amount: U256,
+    },
+
+    // AccessControlRegistry
+    InitializedManager {
+        root_role: U256,
+        manager: H160,
+        sender: H160,
+    },
+    InitializedRole {
+        role: U256,
+        admin_role: U256,
+        description: String,
+        sender: H160,
+    },
+    RoleGranted {
+        role: U256,
+        account: H160,
+        sender: H160,
+    },
+    RoleRevoked {
+        role: U256,
+        account: H160,
+        sender: H160,
+    },
+
+    // RequesterAuthorizerWithAirnode
+    ExtendedAuthorizationExpiration {
+        airnode: H160,
+        endpoint_id: U256,
+        requester: H160,
+        expiration_timestamp: u32,
+        sender: H160,
+    },
+    RevokedIndefiniteAuthorizationStatus {
+        airnode: H160,
+        endpoint_id: U256,
+        requester: H160,
+        setter: H160,
+        indefinite_authorization_count: U256,
+        sender: H160,
+    },
+    SetAuthorizationExpiration {
+        airnode: H160,
+        endpoint_id: U256,
+        requester: H160,
+        expiration_timestamp: u32,
+        sender: H160,
+    },
+    SetIndefiniteAuthorizationStatus {
+        airnode: H160,
+        endpoint_id: U256,
+        requester: H160,
+        status: bool,
+        indefinite_authorization_count: U256,
+        sender: H160,
+    },
+
+    // AirnodeProtocol (v1)
+    MadeRequest {
+        airnode: H160,
+        request_id: U256,
+        requester: H160,
+        requester_request_count: u64,
+        template_id: U256,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<ABI>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<DecodingError>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
+        sponsor: H160,
+        fulfill_function_id: u64,
+    },
+    // decoded only for the logs of AirnodeProtocol (v1), see `Protocol`
+    FulfilledRequestV1 {
+        airnode: H160,
+        request_id: U256,
+        timestamp: u32,
+        data: Vec<U256>,
+    },
+    FailedRequestV1 {
+        airnode: H160,
+        request_id: U256,
+        timestamp: u32,
+        error_message: String,
+    },
+    MadeRequestRelayed {
+        relayer: H160,
+        request_id: U256,
+        airnode: H160,
+        requester: H160,
+        requester_request_count: u64,
+        template_id: U256,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<ABI>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<DecodingError>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
+        sponsor: H160,
+        fulfill_function_id: u64,
+    },
+    FulfilledRequestRelayed {
+        relayer: H160,
+        request_id: U256,
+        airnode: H160,
+        timestamp: u32,
+        data: Vec<U256>,
+    },
+    FailedRequestRelayed {
+        relayer: H160,
+        request_id: U256,
+        airnode: H160,
+        timestamp: u32,
+        error_message: String,
+    },
+    StoredTemplate {
+        template_id: U256,
+        endpoint_id: U256,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<ABI>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<DecodingError>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
+    },
+    StoredSubscription {
+        subscription_id: U256,
+        chain_id: u64,
+        airnode: H160,
+        template_id: U256,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        parameters: Option<ABI>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<DecodingError>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        data: Option<Vec<U256>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        partial: Option<PartialABI>,
+        // conditions of the subscription, as encoded by the requester
+        conditions: Vec<U256>,
+        relayer: H160,
+        sponsor: H160,
+        requester: H160,
+        fulfill_function_id: u64,
+    },
+    // allocators
+    SetSlot {
+        airnode: H160,
+        slot_index: U256,
+        subscription_id: U256,
+        expiration_timestamp: u32,
+        sender: H160,
     },
 
     // unknown, but ignored, do not fail on this type
@@ -319,83 +558,6 @@ pub enum AirnodeEvent {
     Unknown,
 }
 
-static KNOWN_EVENTS: phf::Map<&'static str, &'static str> = phf_map! {
-    // Pre-Alpha version
-    "8acbd28af1fec329994543393007c74ebc717caab62689ba09fbf938f015d3fc" => "ClientEndorsementStatusUpdated(uint256,address,bool)",
-    "775e78a8e7375d14ad03d31edd0a27b29a055f732bca987abfe8082c16ed7e44" => "ClientFullRequestCreated(bytes32,bytes32,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
-    "aff6f5e5548953a11cbb1cfdd76562512f969b0eba0a2163f2420630d4dda97b" => "ClientRequestCreated(bytes32,bytes32,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
-    "1cfdd5ace64f15111ef8ed9df04364d0e9a9165cccf8386109347e54661ba3ad" => "ClientRequestFailed(bytes32,bytes32)",
-    "1bdbe9e5d42a025a741fc3582eb3cad4ef61ac742d83cc87e545fbd481b926b5" => "ClientRequestFulfilled(bytes32,bytes32,uint256,bytes32)",
-    "0ebeb9b9b5c4baf915e7541c7e0919dd1a58eb06ee596035a50d08d20b9219de" => "ClientRequestFulfilledWithBytes(bytes32,bytes32,uint256,bytes)",
-    "fcbcd5adb2d26ecd4ad50e6267e977fd479fcd0a6c82bde8eea85290ab3b46e6" => "ClientShortRequestCreated(bytes32,bytes32,uint256,address,bytes32,bytes)",
-    "e5687475d94be4622dec0d6fa4db8686e003947facd485b0f4685954b8e93aa8" => "EndpointUpdated(bytes32,bytes32,address[])",
-    "40857340078796a2b6bca551f97b62fffe6ae69e2131195d461862224ee871b6" => "MinBalanceUpdated(bytes32,uint256)",
-    "36ef18ad81b13124b66c80d27059d75bfadf09474c46aee8bb4ae998a921196d" => "ProviderCreated(bytes32,address,string)",
-    "b7de80d002230ae37dd9e25804e78c41517296ad969a962ef5457be94cb8ac6e" => "ProviderUpdated(bytes32,address)",
-    "59e98f4c18a6c92efe8c23bcbd74f0d71e271eebf9a95f9edefdbee17c01f270" => "RequesterCreated(uint256,address)",
-    "de26d3d8fc98a8dab0df21ef2146d313da1a060d635f3ce9b42adab32fa992aa" => "RequesterUpdated(uint256,address)",
-    "2cb6f3105333165ac08235b122e2651dae9c2e70787572aa65bde31fe838d90d" => "RequestFulfilled(bytes32,uint256,bytes32)",
-    "13873a3c5277d69c913bb408d87512468d41afb41113dd46eee917ec4eceb04b" => "RequestFulfilledWithBytes(bytes32,uint256,bytes)",
-    "fa33b8597a1a83305d334562a90f8b4ce657e1b33c081423b6a44792d1cf41a4" => "TemplateCreated(bytes32,bytes32,bytes32,uint256,address,address,bytes4,bytes)",
-    "9e7b58b29aa3b972bb0f457499d0dfd00bf23905b0c3358fb864e7120402aefa" => "WithdrawalFulfilled(bytes32,uint256,bytes32,address,address,uint256)",
-    "3d0ebccb4fc9730699221da0180970852f595ed5c78781346149123cbbe9f1d3" => "WithdrawalRequested(bytes32,uint256,bytes32,address,address)",
-
-    // Beta protocol version
-    "dfa496c578099ee263f6fbdc842c01815924953f92c186099d640f910c1f92de" => "CreatedTemplate(bytes32,address,bytes32,bytes)",
-    "b4a13e8a5b83b6572fd11170aa28965f4b16ce6ed228501322a428b48e34230c" => "DecreasedSelfRank(address,uint256)",
-    "907b7436750d9bb04b635c837b151be449230b1975dac4ba31b01343b41eb75c" => "DecreasedSelfRank(address,address,uint256)",
-    "df7c6cf6c7d32bf473537bcf24259094d6e7cb863700e071f65a4d8a05b6ce5e" => "ErroredBeaconUpdate(bytes32,bytes32,uint256)",
-    "f9b174be67f83278d4516865d1b9ba4576b73e523ea0c2f124ea29152bb1b676" => "ExtendedWhitelistExpiration(address,bytes32,address,address,uint256)",
-    "a9e0c89b898eb7a904617915dc5b5510d539c899810042e9248569b54b9cc2ed" => "ExtendedWhitelistExpiration(bytes32,address,address,uint256)",
-    "8c087e42b178608800a2ea8b3d009bdbbf75e0d23426510c2edd447d4f8b8ebd" => "FailedRequest(address,bytes32)",
-    "c7143b2270cddda57e0087ca5e2a4325657dcab10d10f6b1f9d5ce6b41cb97fc" => "FailedRequest(address,bytes32,string)",
-    "d1cc11d12363af4b6022e66d14b18ba1779ecd85a5b41891349d530fb6eee066" => "FulfilledRequest(address,bytes32,uint256,bytes)",
-    "adb4840bbd5f924665ae7e0e0c83de5c0fb40a98c9b57dba53a6c978127a622e" => "FulfilledWithdrawal(address,address,bytes32,address,uint256)",
-    "3a52c462346de2e9436a3868970892956828a11b9c43da1ed43740b12e1125ae" => "MadeFullRequest(address,bytes32,uint256,uint256,address,bytes32,address,address,address,bytes4,bytes)",
-    "eb39930cdcbb560e6422558a2468b93a215af60063622e63cbb165eba14c3203" => "MadeTemplateRequest(address,bytes32,uint256,uint256,address,bytes32,address,address,address,bytes4,bytes)",
-    "db6e5ad2f932677d9abcb868239c24d484d5512caf71029b8b7c2309aeee760a" => "RequestedBeaconUpdate(bytes32,address,address,bytes32,address)",
-    "d48d52c7c6d0c940f3f8d07591e1800ef3a70daf79929a97ccd80b4494769fc7" => "RequestedWithdrawal(address,address,bytes32,address)",
-    "ebace4380f1ba3ccf701db78879a937b0ad2a9370e98baaba922228f632383e0" => "SetAirnodeXpub(address,string)",
-    "584a7e3e68feb90397faadcb0af28a855e0268ddedf9fce510b4cf57770b9410" => "SetRank(address,address,address,uint256)",
-    "07048cabcdd89c62fecf542621231579eae613db4aeb83794e9c3abf428840ca" => "SetRank(address,address,uint256)",
-    "c2e532a12bbcce2bfa2ef9e4bee80180e4e1b1f78618f0d20bc49a648b577c56" => "SetSponsorshipStatus(address,address,bool)",
-    "5a3b1968640fbb8b12349ea1a58be5c61eaec6e38c11c38652f1d250207103ab" => "SetUpdatePermissionStatus(address,address,bool)",
-    "375ee45428e158031095010484fd6451af89c501c79d75e390da4e91eb480ce1" => "SetWhitelistExpiration(address,bytes32,address,address,uint256)",
-    "d19e89b7d547ccf349211588a9a1d29461e2ce984b1b1cdbe7150976528b86f1" => "SetWhitelistExpiration(bytes32,address,address,uint256)",
-    "0e8af304f7f920661493a5051df03a3947d58b4f655581e51ab0c014d768d8eb" => "SetWhitelistStatusPastExpiration(address,bytes32,address,address,bool)",
-    "527f03e7cb13db04fc83c2332106b6087c66d253bca13289f5d91d8e73796d11" => "SetWhitelistStatusPastExpiration(bytes32,address,address,bool)",
-    "c3dafe3cca75d9d099b1941e05b199870f55b853dd49784a96359ac26f01bf6d" => "TransferredMetaAdminStatus(address)",
-    "cb9c65e5f99c20826a331174f56e8c536161ecb0b5d598267e79c83567d477f1" => "UpdatedBeacon(bytes32,bytes32,int224,uint32)",
-
-    // Topics from other versions/branches (probably abandoned or deprecated):
-    // "cee45ff00381d88710040af9bd1c13ee3176f83efd36b33f2330cca74e53000b" => "AirnodeParametersSet(bytes32,address,string,address[])",
-    // "450d1b4c87d285de4ba1f262cca585d6bb3184ca909e14263ac31a044682d429" => "CheckAuthorizationStatus(bytes32,bytes32,bytes32,uint256,address,address)",
-    // "c39b3f29cca3bbf148a7d996d34223a627c7df660f04f3d28e915ae11eea446c" => "CheckAuthorizationStatuses(bytes32,bytes32[],bytes32[],uint256[],address[],address[])",
-    // "d859fb74ec5a83da09d56bb4211667f87fc36cdec8ccdd9ddc7115c99923e75e" => "ClientEndorsementStatusSet(uint256,address,bool)",
-    // "e8ae99161b1547fd1c6ff3cb9660293fa4cd770fd52f72ff0362d64d8bccc08e" => "ClientFullRequestCreated(bytes32,bytes32,uint256,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
-    // "8339fddbb81e588a9ed04dec82ee9ae6c7a185f44835adaaa2ace50ce3a14aaf" => "ClientRequestCreated(bytes32,bytes32,uint256,uint256,address,bytes32,uint256,address,address,bytes4,bytes)",
-    // "cde46e28d8d3e348e5f5b4fcc511fe3b1f9b0f549cd8332f0da31802a6f2bf61" => "ClientRequestFulfilled(bytes32,bytes32,uint256,bytes)",
-    // "14c9c5c9712ca2ec34c62aaeb946d2762f472ad43612ad1a7fb549e274715e39" => "CreateRequester(address)",
-    // "57fd46b3414df2b35458ac6ab35236164c21fab7f784d250882d66a197e8cdaf" => "CreateTemplate(bytes32,bytes32,bytes)",
-    // "ddac55da39d52cd1fe3f9a6832cb6fa4c1fb45d755c47f984cc5a1c48aa774cf" => "DecreasedSelfRank(bytes32,address,uint256)",
-    // "98f45a41d9f64ecabfb00dc5bc4f6aea7e50c2afb9eebee8936be5dbcad03dc2" => "ExtendedWhitelistExpiration(bytes32,address,uint256,address)",
-    // "3338d71fe7787f614d15e7da994eaa07eb176c0e5193ba05cc0b71cc10017665" => "Fail(bytes32,bytes32,address,bytes4)",
-    // "a3ecafb4214d6c3c8dbf95d5fe8e92051c9ac156e0584a00b2a7e16b2df48c0a" => "Fulfill(bytes32,bytes32,uint256,bytes,address,bytes4)",
-    // "8ff67212697f6648c4c6355eb59d2e859c8b0de2e42e5c226e43626ec8c38355" => "FulfillWithdrawal(bytes32,bytes32,uint256,address)",
-    // "cf16e219d0e9946ba140541a449711b0d9e7e3600c4fd63f3c5c1e6f7e78f789" => "MakeFullRequest(bytes32,bytes32,uint256,address,address,bytes4,bytes)",
-    // "bc7e6375ca8aaa60eba7a35af9f5692d6de892654b6d794613be986377467d90" => "MakeRequest(bytes32,uint256,address,address,bytes4,bytes)",
-    // "19233d41da09723f3b102e7a0e192a478c3cd72aa3269f529824b8cc632043a3" => "RequestWithdrawal(bytes32,uint256,address,address)",
-    // "430ace8db43187f56ab9e66a2421b7044fa806a347e0a402a646dc9d0edb0cb1" => "SetApi3Admin(address)",
-    // "aeae98ebd6f5c18f7e64fdd4102cb25feb3552afa6f81285fb4f942e2e41fc3f" => "SetAirnodeParameters(address,string,address[])",
-    // "5a0aa1abecb91b072383fcd5858f29ed6135a87dd2f2ab02e8f77def11f19bc2" => "SetAirnodeParametersAndForwardFunds(address,string,address[])",
-    // "5a570f3df76e0cf7e768a1227f789390627bb125bd73a160596a2559aafdc2da" => "SetClientEndorsementStatus(uint256,address,bool)",
-    // "19b9e44e700d40233866bbf1eaa4d9465d357e53969945a144ef247f39a0836a" => "SetRank(bytes32,address,uint256,address)",
-    // "c980cf6eca9a252c38dc7738fb47cd57dfbf37bda6664adfbab67a09df1af859" => "SetRequesterAdmin(uint256,address)",
-    // "0c4d21ef7140ba56cb38499615ee0b0d3258917f74c778590e597a47ad529ac1" => "SetWhitelistExpiration(bytes32,address,uint256,address)",
-    // "12abbeaa6fd4b14c4a9208f65d65c03b44c354145ba54d20b11474254438408f" => "SetWhitelistStatusPastExpiration(bytes32,address,bool,address)",
-    // "a3c44778bf2c4729d112c7eeee01a2a79be70e58e3fe0b2a25d6c3562f73ab83" => "TemplateCreated(bytes32,bytes32,bytes32,bytes)",
-};
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AirnodeState {
     address: H160,
@@ -410,7 +572,9 @@ impl AirnodeEvent {
             Self::ClientRequestCreatedA { error, .. } => error.clone().map(|x| x.to_string()),
             Self::ClientShortRequestCreatedA { error, .. } => error.clone().map(|x| x.to_string()),
             Self::CreatedTemplate { error, .. } => error.clone().map(|x| x.to_string()),
-            Self::FailedRequest { error_message, .. } => {
+            Self::FailedRequest { error_message, .. }
+            | Self::FailedRequestV1 { error_message, .. }
+            | Self::FailedRequestRelayed { error_message, .. } => {
                 if error_message.len() > 0 {
                     Some(error_message.clone())
                 } else {
@@ -420,6 +584,10 @@ impl AirnodeEvent {
             Self::MadeFullRequest { error, .. } => error.clone().map(|x| x.to_string()),
             Self::MadeTemplateRequest { error, .. } => error.clone().map(|x| x.to_string()),
             Self::TemplateCreatedA { error, .. } => error.clone().map(|x| x.to_string()),
+            Self::MadeRequest { error, .. } => error.clone().map(|x| x.to_string()),
+            Self::MadeRequestRelayed { error, .. } => error.clone().map(|x| x.to_string()),
+            Self::StoredTemplate { error, .. } => error.clone().map(|x| x.to_string()),
+            Self::StoredSubscription { error, .. } => error.clone().map(|x| x.to_string()),
             _ => None,
         }
     }
@@ -441,7 +609,7 @@ impl AirnodeEvent {
         requests::get_endpoint_id(self)
     }
 
-    // airnode: only for beta-protocol
+    // airnode: only for beta-protocol and later
     pub fn get_airnode(&self) -> Option<H160> {
         requests::get_airnode(self)
     }
@@ -454,583 +622,655 @@ impl AirnodeEvent {
     pub fn get_template_id(&self) -> Option<U256> {
         requests::get_template_id(self)
     }
+
+    // data_feed_id: ID of the beacon or beacon set, only for data feeds (and beta `UpdatedBeacon`)
+    pub fn get_data_feed_id(&self) -> Option<U256> {
+        requests::get_data_feed_id(self)
+    }
+
+    // subscription_id: only for PSP subscriptions, their allocator slots and the beacons updated by them
+    pub fn get_subscription_id(&self) -> Option<U256> {
+        requests::get_subscription_id(self)
+    }
+
     pub fn get_fulfill_function_id(&self) -> Option<u64> {
         requests::get_fulfill_function_id(self)
     }
 
-    pub fn from_log(log: &web3::types::Log) -> Result<Self, EventParseError> {
-        let t0 = log.topics[0];
+    // parameters of the request that could be decoded, when the full decoding has failed
+    pub fn get_partial_parameters(&self) -> Option<&PartialABI> {
+        requests::get_partial_parameters(self)
+    }
 
-        if t0 == hex!("8acbd28af1fec329994543393007c74ebc717caab62689ba09fbf938f015d3fc").into() {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::ClientEndorsementStatusUpdatedA {
-                requester_index: r.value(),
-                client_address: r.address(),
-                endorsement_status: r.bool(),
-            });
-        } else if t0
-            == hex!("775e78a8e7375d14ad03d31edd0a27b29a055f732bca987abfe8082c16ed7e44").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            let provider_id = r.value();
-            let request_id = r.value();
-            let no_requests = r.value().as_u64();
-            let client_address = r.address();
-            let endpoint_id = r.value();
-            let requester_index = r.value();
-            let designated_wallet = r.address();
-            let fulfill_address = r.address();
-            let fulfill_function_id = U256::from(r.value().as_ref()[3] / 0x100000000).as_u64();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            // chunks.iter().enumerate().for_each(|(i, u)| println!("{:04x?}: {}", i*0x20, serde_json::to_string(u).unwrap()));
-            // println!("ClientFullRequestCreated decoded: {:#?}", ABI::decode(&chunks));
-            return Ok(Self::ClientFullRequestCreatedA {
-                provider_id,
-                request_id,
-                no_requests,
-                client_address,
-                endpoint_id,
-                requester_index,
-                designated_wallet,
-                fulfill_address,
-                fulfill_function_id, //.as_u64(),
-                parameters,
-                error,
-                data,
-            });
-        } else if t0
-            == hex!("aff6f5e5548953a11cbb1cfdd76562512f969b0eba0a2163f2420630d4dda97b").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            let provider_id = r.value();
-            let request_id = r.value();
-            let no_requests = r.value().as_u64();
-            let client_address = r.address();
-            let template_id = r.value();
-            let requester_index = r.value();
-            let designated_wallet = r.address();
-            let fulfill_address = r.address();
-            let fulfill_function_id = U256::from(r.value().as_ref()[3] / 0x100000000).as_u64();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::ClientRequestCreatedA {
-                provider_id,
-                request_id,
-                no_requests,
-                client_address,
-                template_id,
-                requester_index,
-                designated_wallet,
-                fulfill_address,
-                fulfill_function_id, //.as_u64(),
-                parameters,
-                error,
-                data,
-            });
-        } else if t0
-            == hex!("1cfdd5ace64f15111ef8ed9df04364d0e9a9165cccf8386109347e54661ba3ad").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(0)).unwrap();
-            return Ok(Self::ClientRequestFailedA {
-                provider_id: r.value(),
-                request_id: r.value(),
-            });
-        } else if t0
-            == hex!("1bdbe9e5d42a025a741fc3582eb3cad4ef61ac742d83cc87e545fbd481b926b5").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(2)).unwrap();
-            return Ok(Self::ClientRequestFulfilledA {
-                provider_id: r.value(),
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-                data: r.values(),
-            });
-        } else if t0
-            == hex!("0ebeb9b9b5c4baf915e7541c7e0919dd1a58eb06ee596035a50d08d20b9219de").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            return Ok(Self::ClientRequestFulfilledWithBytesA {
-                provider_id: r.value(),
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-                data: r.values(),
-            });
-        } else if t0
-            == hex!("fcbcd5adb2d26ecd4ad50e6267e977fd479fcd0a6c82bde8eea85290ab3b46e6").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            let provider_id = r.value();
-            let request_id = r.value();
-            let no_requests = r.value().as_u64();
-            let client_address = r.address();
-            let template_id = r.value();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::ClientShortRequestCreatedA {
-                provider_id,
-                request_id,
-                no_requests,
-                client_address,
-                template_id,
-                parameters,
-                error,
-                data,
-            });
-        } else if t0
-            == hex!("e5687475d94be4622dec0d6fa4db8686e003947facd485b0f4685954b8e93aa8").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            return Ok(Self::EndpointUpdatedA {
-                provider_id: r.value(),
-                endpoint_id: r.value(),
-                authorizers: r.addresses(),
-            });
-        } else if t0
-            == hex!("40857340078796a2b6bca551f97b62fffe6ae69e2131195d461862224ee871b6").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(1)).unwrap();
-            return Ok(Self::MinBalanceUpdatedA {
-                provider_id: r.value(),
-                min_balance: r.value(),
-            });
-        } else if t0
-            == hex!("36ef18ad81b13124b66c80d27059d75bfadf09474c46aee8bb4ae998a921196d").into()
-        {
-            let mut r = LogReader::new(&log, 1, None).unwrap();
-            return Ok(Self::ProviderCreatedA {
-                provider_id: r.value(),
-                admin: r.address(),
-                xpub: r.text(),
-            });
-        } else if t0
-            == hex!("b7de80d002230ae37dd9e25804e78c41517296ad969a962ef5457be94cb8ac6e").into()
-        {
-            let mut r = LogReader::new(&log, 1, None).unwrap();
-            return Ok(Self::ProviderUpdatedA {
-                provider_id: r.value(),
-                admin: r.address(),
-            });
-        } else if t0
-            == hex!("59e98f4c18a6c92efe8c23bcbd74f0d71e271eebf9a95f9edefdbee17c01f270").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(1)).unwrap();
-            return Ok(Self::RequesterCreatedA {
-                requester_index: r.value(),
-                admin: r.address(),
-            });
-        } else if t0
-            == hex!("de26d3d8fc98a8dab0df21ef2146d313da1a060d635f3ce9b42adab32fa992aa").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(1)).unwrap();
-            return Ok(Self::RequesterUpdatedA {
-                requester_index: r.value(),
-                admin: r.address(),
-            });
-        } else if t0
-            == hex!("2cb6f3105333165ac08235b122e2651dae9c2e70787572aa65bde31fe838d90d").into()
-        {
-            let mut r = LogReader::new(&log, 0, Some(3)).unwrap();
-            return Ok(Self::RequestFulfilledA {
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-                data: r.value(),
-            });
-        } else if t0
-            == hex!("13873a3c5277d69c913bb408d87512468d41afb41113dd46eee917ec4eceb04b").into()
-        {
-            let mut r = LogReader::new(&log, 0, None).unwrap();
-            return Ok(Self::RequestFulfilledWithBytesA {
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-                data: r.values(),
-            });
-        } else if t0
-            == hex!("fa33b8597a1a83305d334562a90f8b4ce657e1b33c081423b6a44792d1cf41a4").into()
-        {
-            let mut r = LogReader::new(&log, 1, None).unwrap();
-            let template_id = r.value();
-            let provider_id = r.value();
-            let endpoint_id = r.value();
-            let requester_index: U256 = r.value();
-            let designated_wallet = r.address();
-            let fulfill_address = r.address();
-            let fulfill_function_id = U256::from(r.value().as_ref()[3] / 0x100000000).as_u64();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::TemplateCreatedA {
-                template_id,
-                provider_id,
-                endpoint_id,
-                requester_index,
-                designated_wallet,
-                fulfill_address,
-                fulfill_function_id,
-                parameters,
-                error,
-                data,
-            });
-        } else if t0
-            == hex!("9e7b58b29aa3b972bb0f457499d0dfd00bf23905b0c3358fb864e7120402aefa").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(3)).unwrap();
-            return Ok(Self::WithdrawalFulfilledA {
-                provider_id: r.value(),
-                requester_index: r.value(),
-                withdrawal_request_id: r.value(),
-                designated_wallet: r.address(),
-                destination: r.address(),
-                amount: r.value(),
-            });
-        } else if t0
-            == hex!("3d0ebccb4fc9730699221da0180970852f595ed5c78781346149123cbbe9f1d3").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(2)).unwrap();
-            return Ok(Self::WithdrawalFulfilledA {
-                provider_id: r.value(),
-                requester_index: r.value(),
-                withdrawal_request_id: r.value(),
-                designated_wallet: r.address(),
-                destination: r.address(),
-                amount: r.value(),
-            });
-        } else if t0
-            == hex!("dfa496c578099ee263f6fbdc842c01815924953f92c186099d640f910c1f92de").into()
-        {
-            let mut r = LogReader::new(&log, 1, None).unwrap();
-            let template_id = r.value();
-            let airnode = r.address();
-            let endpoint_id = r.value();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::CreatedTemplate {
-                template_id,
-                airnode,
-                endpoint_id,
-                parameters,
-                data,
-                error,
-            });
-        } else if t0
-            == hex!("b4a13e8a5b83b6572fd11170aa28965f4b16ce6ed228501322a428b48e34230c").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(1)).unwrap();
-            return Ok(Self::DecreasedSelfRank {
-                admin: r.address(),
-                new_rank: r.value(),
-            });
-        } else if t0
-            == hex!("907b7436750d9bb04b635c837b151be449230b1975dac4ba31b01343b41eb75c").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::DecreasedSelfRankAdminned {
-                adminned: r.address(),
-                admin: r.address(),
-                new_rank: r.value(),
-            });
-        } else if t0
-            == hex!("df7c6cf6c7d32bf473537bcf24259094d6e7cb863700e071f65a4d8a05b6ce5e").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(2)).unwrap();
-            return Ok(Self::ErroredBeaconUpdate {
-                template_id: r.value(),
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-            });
-        } else if t0
-            == hex!("f9b174be67f83278d4516865d1b9ba4576b73e523ea0c2f124ea29152bb1b676").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(1)).unwrap();
-            return Ok(Self::ExtendedWhitelistExpiration {
-                airnode: r.address(),
-                endpoint_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                expiration: r.value().as_u64(),
-            });
-        } else if t0
-            == hex!("a9e0c89b898eb7a904617915dc5b5510d539c899810042e9248569b54b9cc2ed").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::ExtendedWhitelistExpirationTpl {
-                template_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                expiration: r.value().as_u64(),
-            });
-        } else if t0
-            == hex!("8c087e42b178608800a2ea8b3d009bdbbf75e0d23426510c2edd447d4f8b8ebd").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(0)).unwrap();
-            return Ok(Self::FailedRequest {
-                airnode: r.address(),
-                request_id: r.value(),
-                error_message: "".to_owned(),
-            });
-        } else if t0
-            == hex!("c7143b2270cddda57e0087ca5e2a4325657dcab10d10f6b1f9d5ce6b41cb97fc").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::FailedRequest {
-                airnode: r.address(),
-                request_id: r.value(),
-                error_message: r.text(),
-            });
-        } else if t0
-            == hex!("d1cc11d12363af4b6022e66d14b18ba1779ecd85a5b41891349d530fb6eee066").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            return Ok(Self::FulfilledRequest {
-                airnode: r.address(),
-                request_id: r.value(),
-                status_code: r.value().as_u64(),
-                data: r.values(),
-            });
-        } else if t0
-            == hex!("adb4840bbd5f924665ae7e0e0c83de5c0fb40a98c9b57dba53a6c978127a622e").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(2)).unwrap();
-            return Ok(Self::FulfilledWithdrawal {
-                airnode: r.address(),
-                sponsor: r.address(),
-                withdrawal_request_id: r.value(),
-                sponsor_wallet: r.address(),
-                amount: r.value(),
-            });
-        } else if t0
-            == hex!("3a52c462346de2e9436a3868970892956828a11b9c43da1ed43740b12e1125ae").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(2)).unwrap();
-            let airnode = r.address();
-            let request_id = r.value();
-            let requester_request_count = r.value().as_u64();
-            let chain_id = r.value().as_u64();
-            let requester = r.address();
-            let endpoint_id = r.value();
-            let sponsor = r.address();
-            let sponsor_wallet = r.address();
-            let fulfill_address = r.address();
-            let fulfill_function_id = U256::from(r.value().as_ref()[3] / 0x100000000).as_u64();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::MadeFullRequest {
-                airnode,
-                request_id,
-                requester_request_count,
-                chain_id,
-                requester,
-                endpoint_id,
-                sponsor,
-                sponsor_wallet,
-                fulfill_address,
-                fulfill_function_id,
-                parameters,
-                error,
-                data,
-            });
-        } else if t0
-            == hex!("eb39930cdcbb560e6422558a2468b93a215af60063622e63cbb165eba14c3203").into()
-        {
-            let mut r = LogReader::new(&log, 2, None).unwrap();
-            let airnode = r.address();
-            let request_id = r.value();
-            let requester_request_count = r.value().as_u64();
-            let chain_id = r.value().as_u64();
-            let requester = r.address();
-            let template_id = r.value();
-            let sponsor = r.address();
-            let sponsor_wallet = r.address();
-            let fulfill_address = r.address();
-            let fulfill_function_id = U256::from(r.value().as_ref()[3] / 0x100000000).as_u64();
-            r.skip();
-            r.skip();
-            let chunks = r.values();
-            let (parameters, error, data) = match ABI::decode(&chunks, false) {
-                Ok(x) => (Some(x), None, None),
-                Err(e) => (None, Some(e), Some(chunks)),
-            };
-            return Ok(Self::MadeTemplateRequest {
-                airnode,
+    // decodes data of the fulfillment, using `_type` and `_times` of the originating request.
+    // None if it is not a fulfillment or the request has no `_type` parameter
+    pub fn decode_fulfillment(
+        &self,
+        request_parameters: &ABI,
+    ) -> Option<Result<Vec<ResponseValue>, ResponseError>> {
+        let data = requests::get_fulfillment_data(self)?;
+        let reserved = ReservedParameters::from_abi(request_parameters)?;
+        Some(reserved.decode_response(&data))
+    }
+
+    /// decodes the event from the log by the known signature in its first topic.
+    /// Logs with unknown signatures are returned as `Unknown`
+    pub fn from_log<L: RawLog + ?Sized>(log: &L) -> Result<Self, EventParseError> {
+        Self::from_protocol_log(log, Protocol::Any)
+    }
+
+    /// decodes the event from the log of the contract of the given protocol.
+    /// Events of AirnodeProtocol (v1) are told apart from the beta protocol only this way
+    pub fn from_protocol_log<L: RawLog + ?Sized>(
+        log: &L,
+        protocol: Protocol,
+    ) -> Result<Self, EventParseError> {
+        let topics = log.topics();
+        if topics.is_empty() {
+            return Err(EventParseError::NoTopics);
+        }
+        match events::find(&topics, protocol) {
+            Some(def) => def.decode(log),
+            None => Ok(Self::Unknown),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use airnode_abi::Param;
+    use ethereum_types::H256;
+    use hex_literal::hex;
+
+    fn log(topics: Vec<H256>, data: Vec<u8>) -> LogEntry {
+        LogEntry::new(topics, data)
+    }
+
+    fn word(value: U256) -> Vec<u8> {
+        let mut out = vec![0u8; 32];
+        value.to_big_endian(&mut out);
+        out
+    }
+
+    fn address(value: H160) -> Vec<u8> {
+        H256::from(value).as_bytes().to_vec()
+    }
+
+    #[test]
+    fn it_decodes_template_request() {
+        let airnode = H160::from_low_u64_be(0xa1);
+        let params = ABI::new(vec![Param::String32 {
+            name: "from".to_owned(),
+            value: "ETH".to_owned(),
+        }])
+        .to_bytes()
+        .unwrap();
+        let mut data = vec![];
+        data.extend(word(U256::from(7))); // requesterRequestCount
+        data.extend(word(U256::from(1))); // chainId
+        data.extend(address(H160::from_low_u64_be(0xb2))); // requester
+        data.extend(word(U256::from(0x7e))); // templateId
+        data.extend(address(H160::from_low_u64_be(0xc3))); // sponsor
+        data.extend(address(H160::from_low_u64_be(0xd4))); // sponsorWallet
+        data.extend(address(H160::from_low_u64_be(0xe5))); // fulfillAddress
+        data.extend(hex!(
+            "48a4157c00000000000000000000000000000000000000000000000000000000"
+        ));
+        data.extend(word(U256::from(9 * 32))); // offset of parameters
+        data.extend(word(U256::from(params.len())));
+        data.extend(params);
+        let log = log(
+            vec![
+                hex!("eb39930cdcbb560e6422558a2468b93a215af60063622e63cbb165eba14c3203").into(),
+                H256::from(airnode),
+                H256::from_low_u64_be(0x1d),
+            ],
+            data,
+        );
+        match AirnodeEvent::from_log(&log).unwrap() {
+            AirnodeEvent::MadeTemplateRequest {
+                airnode: a,
                 request_id,
                 requester_request_count,
                 chain_id,
-                requester,
                 template_id,
-                sponsor,
                 sponsor_wallet,
-                fulfill_address,
                 fulfill_function_id,
                 parameters,
                 error,
+                ..
+            } => {
+                assert_eq!(a, airnode);
+                assert_eq!(request_id, U256::from(0x1d));
+                assert_eq!(requester_request_count, 7);
+                assert_eq!(chain_id, 1);
+                assert_eq!(template_id, U256::from(0x7e));
+                assert_eq!(sponsor_wallet, H160::from_low_u64_be(0xd4));
+                assert_eq!(fulfill_function_id, 0x48a4157c);
+                assert_eq!(parameters.unwrap().get_string("from"), Ok("ETH"));
+                assert!(error.is_none());
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_decodes_arrays_by_offset() {
+        let authorizers = vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)];
+        let mut data = vec![];
+        data.extend(word(U256::from(32)));
+        data.extend(word(U256::from(2)));
+        authorizers.iter().for_each(|a| data.extend(address(*a)));
+        let log = log(
+            vec![
+                hex!("e5687475d94be4622dec0d6fa4db8686e003947facd485b0f4685954b8e93aa8").into(),
+                H256::from_low_u64_be(3),
+                H256::from_low_u64_be(4),
+            ],
+            data,
+        );
+        match AirnodeEvent::from_log(&log).unwrap() {
+            AirnodeEvent::EndpointUpdatedA {
+                provider_id,
+                endpoint_id,
+                authorizers: list,
+            } => {
+                assert_eq!(provider_id, U256::from(3));
+                assert_eq!(endpoint_id, U256::from(4));
+                assert_eq!(list, authorizers);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_classifies_unknown_logs() {
+        let unknown = log(vec![H256::from_low_u64_be(1)], vec![]);
+        assert!(matches!(
+            AirnodeEvent::from_log(&unknown),
+            Ok(AirnodeEvent::Unknown)
+        ));
+        let anonymous = log(vec![], vec![]);
+        assert!(matches!(
+            AirnodeEvent::from_log(&anonymous),
+            Err(EventParseError::NoTopics)
+        ));
+        // indexed parameters are missing
+        let broken = log(
+            vec![hex!("c3dafe3cca75d9d099b1941e05b199870f55b853dd49784a96359ac26f01bf6d").into()],
+            vec![],
+        );
+        let err = AirnodeEvent::from_log(&broken).unwrap_err();
+        assert_eq!(err.event(), Some("TransferredMetaAdminStatus"));
+        assert!(matches!(err.cause(), EventParseError::InvalidTopics(0, 1)));
+    }
+
+    #[test]
+    fn it_reports_context_of_errors() {
+        let topic = H256::from(hex!(
+            "cb9c65e5f99c20826a331174f56e8c536161ecb0b5d598267e79c83567d477f1"
+        ));
+        // timestamp is missing in the data
+        let mut data = word(U256::from(0x1d));
+        data.extend(word(U256::from(1000)));
+        let truncated = log(vec![topic, H256::from_low_u64_be(0x7e)], data);
+        let err = AirnodeEvent::from_log(&truncated).unwrap_err();
+        assert_eq!(err.event(), Some("UpdatedBeacon"));
+        assert_eq!(err.topic(), Some(topic));
+        assert_eq!(err.field(), Some("timestamp"));
+        assert_eq!(err.offset(), Some(64));
+        assert!(matches!(err.cause(), EventParseError::NoData(64)));
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "UpdatedBeacon event {:?}: field timestamp: no data at 64",
+                topic
+            )
+        );
+    }
+
+    // the vectors are synthetic: they are encoded by the rules of int224 and uint32 in the ABI,
+    // no log of a beacon update was taken from the chain for them
+    #[test]
+    fn it_decodes_beacon_updates() {
+        let decode = |value: [u8; 32]| {
+            let mut data = word(U256::from(0x1d)); // requestId
+            data.extend(value);
+            data.extend(hex!(
+                "0000000000000000000000000000000000000000000000000000000062590080"
+            ));
+            let log = log(
+                vec![
+                    hex!("cb9c65e5f99c20826a331174f56e8c536161ecb0b5d598267e79c83567d477f1").into(),
+                    H256::from_low_u64_be(0x7e),
+                ],
                 data,
-            });
-        } else if t0
-            == hex!("db6e5ad2f932677d9abcb868239c24d484d5512caf71029b8b7c2309aeee760a").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(2)).unwrap();
-            return Ok(Self::RequestedBeaconUpdate {
-                template_id: r.value(),
-                sponsor: r.address(),
-                requester: r.address(),
-                request_id: r.value(),
-                sponsor_wallet: r.address(),
-            });
-        } else if t0
-            == hex!("d48d52c7c6d0c940f3f8d07591e1800ef3a70daf79929a97ccd80b4494769fc7").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::RequestedWithdrawal {
-                airnode: r.address(),
-                sponsor: r.address(),
-                withdrawal_request_id: r.value(),
-                sponsor_wallet: r.address(),
-            });
-        } else if t0
-            == hex!("ebace4380f1ba3ccf701db78879a937b0ad2a9370e98baaba922228f632383e0").into()
-        {
-            let mut r = LogReader::new(&log, 1, None).unwrap();
-            return Ok(Self::SetAirnodeXpub {
-                airnode: r.address(),
-                xpub: r.text(),
-            });
-        } else if t0
-            == hex!("584a7e3e68feb90397faadcb0af28a855e0268ddedf9fce510b4cf57770b9410").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::SetRankAdminned {
-                adminned: r.address(),
-                caller_admin: r.address(),
-                target_admin: r.address(),
-                new_rank: r.value(),
-            });
-        } else if t0
-            == hex!("07048cabcdd89c62fecf542621231579eae613db4aeb83794e9c3abf428840ca").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::SetRank {
-                caller_admin: r.address(),
-                target_admin: r.address(),
-                new_rank: r.value(),
-            });
-        } else if t0
-            == hex!("c2e532a12bbcce2bfa2ef9e4bee80180e4e1b1f78618f0d20bc49a648b577c56").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::SetSponsorshipStatus {
-                sponsor: r.address(),
-                requester: r.address(),
-                sponsorship_status: r.bool(),
-            });
-        } else if t0
-            == hex!("5a3b1968640fbb8b12349ea1a58be5c61eaec6e38c11c38652f1d250207103ab").into()
-        {
-            let mut r = LogReader::new(&log, 2, Some(1)).unwrap();
-            return Ok(Self::SetUpdatePermissionStatus {
-                sponsor: r.address(),
-                update_requester: r.address(),
-                status: r.bool(),
-            });
-        } else if t0
-            == hex!("375ee45428e158031095010484fd6451af89c501c79d75e390da4e91eb480ce1").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::SetWhitelistExpiration {
-                airnode: r.address(),
-                endpoint_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                expiration: r.value().as_u64(),
-            });
-        } else if t0
-            == hex!("d19e89b7d547ccf349211588a9a1d29461e2ce984b1b1cdbe7150976528b86f1").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::SetWhitelistExpirationTpl {
-                template_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                expiration: r.value().as_u64(),
-            });
-        } else if t0
-            == hex!("0e8af304f7f920661493a5051df03a3947d58b4f655581e51ab0c014d768d8eb").into()
-        {
-            let mut r = LogReader::new(&log, 4, Some(1)).unwrap();
-            return Ok(Self::SetWhitelistStatusPastExpiration {
-                airnode: r.address(),
-                endpoint_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                status: r.bool(),
-            });
-        } else if t0
-            == hex!("527f03e7cb13db04fc83c2332106b6087c66d253bca13289f5d91d8e73796d11").into()
-        {
-            let mut r = LogReader::new(&log, 3, Some(1)).unwrap();
-            return Ok(Self::SetWhitelistStatusPastExpirationTpl {
-                template_id: r.value(),
-                user: r.address(),
-                admin: r.address(),
-                status: r.bool(),
-            });
-        } else if t0
-            == hex!("c3dafe3cca75d9d099b1941e05b199870f55b853dd49784a96359ac26f01bf6d").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(0)).unwrap();
-            return Ok(Self::TransferredMetaAdminStatus {
-                meta_admin: r.address(),
-            });
-        } else if t0
-            == hex!("cb9c65e5f99c20826a331174f56e8c536161ecb0b5d598267e79c83567d477f1").into()
-        {
-            let mut r = LogReader::new(&log, 1, Some(2)).unwrap();
-            let template_id = r.value();
-            let request_id = r.value();
-            let (value, timestamp) = r.value224_32();
-            return Ok(Self::UpdatedBeacon {
+            );
+            AirnodeEvent::from_log(&log)
+        };
+        // 3161.45 with 18 decimals
+        let positive = hex!("0000000000000000000000000000000000000000000000ab61eea8eb86b10000");
+        match decode(positive).unwrap() {
+            AirnodeEvent::UpdatedBeacon {
                 template_id,
                 request_id,
                 value,
                 timestamp,
-            });
+            } => {
+                assert_eq!(template_id, U256::from(0x7e));
+                assert_eq!(request_id, U256::from(0x1d));
+                assert_eq!(value.to_string(), "3161450000000000000000");
+                assert_eq!(timestamp, 1650000000);
+            }
+            x => panic!("unexpected {:?}", x),
         }
+        // -273.15 with 18 decimals
+        let negative = hex!("fffffffffffffffffffffffffffffffffffffffffffffff131484235f20d0000");
+        match decode(negative).unwrap() {
+            AirnodeEvent::UpdatedBeacon { value, .. } => {
+                assert_eq!(value, I256::from(-273150000000000000000i128));
+                assert_eq!(
+                    serde_json::to_value(value).unwrap(),
+                    "-273150000000000000000"
+                );
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        // negative int224, that is not sign-extended to 256 bits
+        let broken = hex!("00000000fffffffffffffffffffffffffffffffffffffff131484235f20d0000");
+        let err = decode(broken).unwrap_err();
+        assert_eq!(err.field(), Some("value"));
+        assert_eq!(err.offset(), Some(32));
+        assert!(matches!(err.cause(), EventParseError::OutOfRange(kind) if kind == "int224"));
+    }
 
-        let topic_str = format!("{:?}", t0).chars().skip(2).collect::<String>();
-        match KNOWN_EVENTS.get(&topic_str) {
-            Some(_title) => {
-                // println!("{} topic={:?}", title, t0);
-                Ok(Self::Unclassified)
+    /// decodes logs of the fixture, that are made up in the format of `eth_getLogs` result
+    fn fixture(name: &str, protocol: Protocol) -> Vec<AirnodeEvent> {
+        let path = format!(
+            "{}/fixtures/synthetic/{}.json",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        );
+        let logs: Vec<LogEntry> =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        logs.iter()
+            .map(|l| AirnodeEvent::from_protocol_log(l, protocol).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn it_decodes_fixtures() {
+        let cases: Vec<(&str, Protocol, Vec<&str>)> = vec![
+            (
+                "airnode_rrp_v0",
+                Protocol::Any,
+                vec![
+                    "CreatedTemplate",
+                    "SetSponsorshipStatus",
+                    "FulfilledRequestV0",
+                    "FailedRequest",
+                    "RequestedWithdrawal",
+                    "FulfilledWithdrawal",
+                ],
+            ),
+            (
+                "data_feeds",
+                Protocol::Any,
+                vec![
+                    "UpdatedBeaconWithSignedData",
+                    "UpdatedBeaconSetWithBeacons",
+                    "SetDapiName",
+                    "UpdatedOevProxyBeaconWithSignedData",
+                    "UpdatedBeaconWithPsp",
+                ],
+            ),
+            (
+                "authorization",
+                Protocol::Any,
+                vec![
+                    "InitializedRole",
+                    "RoleGranted",
+                    "SetIndefiniteAuthorizationStatus",
+                    "ExtendedAuthorizationExpiration",
+                ],
+            ),
+            (
+                "airnode_protocol_v1",
+                Protocol::AirnodeProtocolV1,
+                vec![
+                    "MadeRequest",
+                    "FulfilledRequestV1",
+                    "MadeRequestRelayed",
+                    "FailedRequestRelayed",
+                    "StoredTemplate",
+                    "StoredSubscription",
+                    "SetSlot",
+                ],
+            ),
+        ];
+        for (name, protocol, expected) in cases {
+            let types: Vec<String> = fixture(name, protocol)
+                .iter()
+                .map(|e| {
+                    serde_json::to_value(e).unwrap()["type"]
+                        .as_str()
+                        .unwrap()
+                        .to_owned()
+                })
+                .collect();
+            assert_eq!(types, expected, "types of the events in {}", name);
+        }
+    }
+
+    #[test]
+    fn it_decodes_airnode_rrp_v0_logs() {
+        let events = fixture("airnode_rrp_v0", Protocol::Any);
+        let airnode: H160 = hex!("6238772544f029ecaba5b8b7a5c0d1a7fa4b5b1f").into();
+        let sponsor: H160 = hex!("2c2e12cd6b9c4b1ec9dc0e1c9d5c0e57a9f8e3b1").into();
+        let request_id = U256::from(hex!(
+            "76c7a4b1e8d2f5c9a3b6e1d4f7c2a8b5e9d3f6c1a4b7e2d8f5c9a3b6e1d4f7c2"
+        ));
+
+        match &events[0] {
+            AirnodeEvent::CreatedTemplate {
+                airnode: a,
+                parameters,
+                ..
+            } => {
+                assert_eq!(*a, airnode);
+                let parameters = parameters.as_ref().unwrap();
+                assert_eq!(parameters.get_string("from"), Ok("ETH"));
+                assert_eq!(parameters.get_string("to"), Ok("USD"));
             }
-            None => Ok(Self::Unknown),
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[1] {
+            AirnodeEvent::SetSponsorshipStatus {
+                sponsor: s,
+                sponsorship_status,
+                ..
+            } => {
+                assert_eq!(*s, sponsor);
+                assert!(*sponsorship_status);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[2] {
+            AirnodeEvent::FulfilledRequestV0 { data, .. } => {
+                assert_eq!(data, &vec![U256::from(3161450000u64)]);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[2].get_airnode(), Some(airnode));
+        assert_eq!(events[2].get_request_id(), Some(request_id));
+        match &events[3] {
+            AirnodeEvent::FailedRequest { error_message, .. } => {
+                assert_eq!(error_message, "API call to the provider failed");
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[3].get_request_id(), Some(request_id));
+        match (&events[4], &events[5]) {
+            (
+                AirnodeEvent::RequestedWithdrawal {
+                    withdrawal_request_id: requested,
+                    ..
+                },
+                AirnodeEvent::FulfilledWithdrawal {
+                    withdrawal_request_id: fulfilled,
+                    amount,
+                    ..
+                },
+            ) => {
+                assert_eq!(requested, fulfilled);
+                assert_eq!(*amount, U256::exp10(16) * 2);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_decodes_data_feed_logs() {
+        let events = fixture("data_feeds", Protocol::Any);
+        let beacon_id = U256::from(hex!(
+            "3efb3990846102448c3ee2e47d22f1e5433cd45fa56901abe7ab3ffa054f70b5"
+        ));
+        let beacon_set_id = U256::from(hex!(
+            "4385954e058fbe6b6a744f32a4f89d67aad099f8fb8b23e7ea8dd366ae88151d"
+        ));
+        let feeds: Vec<Option<U256>> = events.iter().map(|e| e.get_data_feed_id()).collect();
+        assert_eq!(
+            feeds,
+            vec![
+                Some(beacon_id),
+                Some(beacon_set_id),
+                Some(beacon_set_id),
+                Some(beacon_id),
+                Some(beacon_id),
+            ]
+        );
+
+        match &events[0] {
+            AirnodeEvent::UpdatedBeaconWithSignedData {
+                value, timestamp, ..
+            } => {
+                assert_eq!(value.to_string(), "1876543200000000000000");
+                assert_eq!(*timestamp, 1700000000);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[2] {
+            AirnodeEvent::SetDapiName { dapi_name, .. } => assert_eq!(dapi_name, "ETH/USD"),
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[3] {
+            AirnodeEvent::UpdatedOevProxyBeaconWithSignedData { proxy, value, .. } => {
+                assert_eq!(
+                    *proxy,
+                    hex!("9b5d4c8f0a2e1b3c5d7e9f1a3b5c7d9e1f3a5b7c").into()
+                );
+                assert_eq!(*value, I256::from(-15_000_000_000_000_000i64));
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(
+            events[3].get_addresses(),
+            vec![hex!("9b5d4c8f0a2e1b3c5d7e9f1a3b5c7d9e1f3a5b7c").into()]
+        );
+        match &events[4] {
+            AirnodeEvent::UpdatedBeaconWithPsp { timestamp, .. } => {
+                assert_eq!(*timestamp, 1699999990)
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_tells_apart_layouts_of_set_dapi_name() {
+        let topic = H256::from(hex!(
+            "f3a9aac9b6ac0f842cb5d9b3491cd5fc1b6a6778d97fd9529f587339865294f5"
+        ));
+        let data_feed_id = H256::from_low_u64_be(0xfeed);
+        let dapi_name = H256::from(hex!(
+            "4554482f55534400000000000000000000000000000000000000000000000000"
+        ));
+        let sender = H160::from_low_u64_be(0x5e);
+        let check = |log: LogEntry| match AirnodeEvent::from_log(&log).unwrap() {
+            AirnodeEvent::SetDapiName {
+                data_feed_id: f,
+                dapi_name: n,
+                sender: s,
+            } => {
+                assert_eq!(f, U256::from(0xfeed));
+                assert_eq!(n, "ETH/USD");
+                assert_eq!(s, sender);
+            }
+            x => panic!("unexpected {:?}", x),
+        };
+
+        // Api3ServerV1
+        check(log(vec![topic, data_feed_id, dapi_name], address(sender)));
+        // DapiServer
+        check(log(
+            vec![topic, dapi_name, H256::from(sender)],
+            data_feed_id.as_bytes().to_vec(),
+        ));
+    }
+
+    #[test]
+    fn it_decodes_authorization_logs() {
+        let events = fixture("authorization", Protocol::Any);
+        let airnode: H160 = hex!("6238772544f029ecaba5b8b7a5c0d1a7fa4b5b1f").into();
+        let requester: H160 = hex!("8a2a2c4b8e1b4a6c3d5e7f9a1b3c5d7e9f1a3b5c").into();
+        let manager: H160 = hex!("c4a3d2b1e0f9a8b7c6d5e4f3a2b1c0d9e8f7a6b5").into();
+        let account: H160 = hex!("1f2e3d4c5b6a79881726354453627180f9e8d7c6").into();
+        let endpoint_id = U256::from(hex!(
+            "fb87102cdabadf905321521ba0b3cbf74ad09c5d400ac2eccdbef8d6143e78c4"
+        ));
+
+        match &events[0] {
+            AirnodeEvent::InitializedRole { description, .. } => {
+                assert_eq!(description, "Indefinite authorizer")
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[0].get_addresses(), vec![manager]);
+        match &events[1] {
+            AirnodeEvent::RoleGranted { role, .. } => assert_eq!(
+                *role,
+                U256::from(hex!(
+                    "a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f90"
+                ))
+            ),
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[1].get_addresses(), vec![account, manager]);
+        match &events[2] {
+            AirnodeEvent::SetIndefiniteAuthorizationStatus {
+                status,
+                indefinite_authorization_count,
+                ..
+            } => {
+                assert!(*status);
+                assert_eq!(*indefinite_authorization_count, U256::one());
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[3] {
+            AirnodeEvent::ExtendedAuthorizationExpiration {
+                expiration_timestamp,
+                ..
+            } => assert_eq!(*expiration_timestamp, 1735689600),
+            x => panic!("unexpected {:?}", x),
+        }
+        for evt in &events[2..] {
+            assert_eq!(evt.get_airnode(), Some(airnode));
+            assert_eq!(evt.get_endpoint_id(), Some(endpoint_id));
+            assert_eq!(evt.get_addresses(), vec![airnode, requester, account]);
+        }
+    }
+
+    #[test]
+    fn it_decodes_airnode_protocol_v1_logs() {
+        let events = fixture("airnode_protocol_v1", Protocol::AirnodeProtocolV1);
+        let airnode: H160 = hex!("6238772544f029ecaba5b8b7a5c0d1a7fa4b5b1f").into();
+        let relayer: H160 = hex!("4e3b2a1908f7e6d5c4b3a29180f7e6d5c4b3a291").into();
+        let template_id = U256::from(hex!(
+            "9c8b7a6f5e4d3c2b1a0f9e8d7c6b5a49382716051f2e3d4c5b6a79887766554a"
+        ));
+        let subscription_id = U256::from(hex!(
+            "7b4e1a8d5c2f9b6e3a0d7c4f1b8e5a2d9c6f3b0e7a4d1c8f5b2e9a6d3c0f7b4e"
+        ));
+
+        match &events[0] {
+            AirnodeEvent::MadeRequest {
+                requester_request_count,
+                parameters,
+                fulfill_function_id,
+                ..
+            } => {
+                assert_eq!(*requester_request_count, 3);
+                assert_eq!(*fulfill_function_id, 0x1decbf18);
+                let parameters = parameters.as_ref().unwrap();
+                assert_eq!(parameters.get_string("from"), Ok("ETH"));
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[0].get_template_id(), Some(template_id));
+        assert_eq!(events[1].get_request_id(), events[0].get_request_id());
+        match &events[1] {
+            AirnodeEvent::FulfilledRequestV1 {
+                timestamp, data, ..
+            } => {
+                assert_eq!(*timestamp, 1700000000);
+                assert_eq!(data, &vec![U256::from(31614500u64)]);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(
+            requests::get_fulfillment_data(&events[1]),
+            Some(vec![U256::from(31614500u64)])
+        );
+        // without the protocol, the fulfillment is decoded as in beta protocol
+        match &fixture("airnode_protocol_v1", Protocol::Any)[1] {
+            AirnodeEvent::FulfilledRequest { status_code, .. } => {
+                assert_eq!(*status_code, 1700000000)
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[2] {
+            AirnodeEvent::MadeRequestRelayed {
+                relayer: r,
+                airnode: a,
+                ..
+            } => {
+                assert_eq!(*r, relayer);
+                assert_eq!(*a, airnode);
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        assert_eq!(events[3].get_request_id(), events[2].get_request_id());
+        assert_eq!(
+            events[3].get_error(),
+            Some("Response exceeds the size limit".to_owned())
+        );
+        assert_eq!(events[4].get_template_id(), Some(template_id));
+        assert!(events[4].get_endpoint_id().is_some());
+        match &events[5] {
+            AirnodeEvent::StoredSubscription {
+                chain_id,
+                parameters,
+                conditions,
+                requester,
+                ..
+            } => {
+                assert_eq!(*chain_id, 1);
+                assert!(parameters.is_some());
+                assert_eq!(conditions.len(), 2);
+                assert_eq!(
+                    *requester,
+                    hex!("8a2a2c4b8e1b4a6c3d5e7f9a1b3c5d7e9f1a3b5c").into()
+                );
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+        match &events[6] {
+            AirnodeEvent::SetSlot {
+                slot_index,
+                expiration_timestamp,
+                ..
+            } => {
+                assert_eq!(*slot_index, U256::from(2));
+                assert_eq!(*expiration_timestamp, 1700003600);
+            }
+            x => panic!("unexpected {:?}", x),
         }
+        let subscriptions: Vec<Option<U256>> =
+            events.iter().map(|e| e.get_subscription_id()).collect();
+        assert_eq!(&subscriptions[..5], &[None, None, None, None, None]);
+        assert_eq!(&subscriptions[5..], &[Some(subscription_id); 2]);
+        assert!(events.iter().all(|e| e.get_airnode() == Some(airnode)
+            || matches!(e, AirnodeEvent::StoredTemplate { .. })));
     }
 }
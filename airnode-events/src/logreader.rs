@@ -1,6 +1,7 @@
-use std::str::FromStr;
+use crate::rawlog::RawLog;
+use crate::signature::{ParamKind, Token};
+use ethereum_types::{H160, H256, U256};
 use thiserror::Error;
-use web3::types::{H160, H256, U256};
 
 #[derive(Error, Debug, Clone)]
 pub enum EventParseError {
@@ -10,6 +11,85 @@ pub enum EventParseError {
     InvalidTopics(usize, usize),
     #[error("{0} data length, {1} bytes expected")]
     InvalidDataSize(usize, usize),
+    #[error("no topic {0}")]
+    NoTopic(usize),
+    #[error("no data at {0}")]
+    NoData(usize),
+    #[error("no value for the field")]
+    NoValue,
+    #[error("invalid value of {0}")]
+    InvalidValue(String),
+    #[error("offset at {0} points outside of the data")]
+    InvalidOffset(usize),
+    #[error("length at {0} exceeds the data")]
+    InvalidLength(usize),
+    #[error("text at {0} is not valid UTF-8")]
+    InvalidText(usize),
+    #[error("value does not fit into {0}")]
+    OutOfRange(String),
+    #[error("invalid signature {0}")]
+    InvalidSignature(String),
+    #[error("field {field}: {source}")]
+    InField {
+        field: String,
+        // offset of the field in the head of the data, `None` for indexed fields
+        offset: Option<usize>,
+        source: Box<EventParseError>,
+    },
+    #[error("{event} event {topic:?}: {source}")]
+    InEvent {
+        event: String,
+        topic: H256,
+        source: Box<EventParseError>,
+    },
+}
+
+impl EventParseError {
+    /// name of the known event, that failed to be decoded
+    pub fn event(&self) -> Option<&str> {
+        match self {
+            Self::InEvent { event, .. } => Some(event),
+            _ => None,
+        }
+    }
+
+    /// first topic of the log, that failed to be decoded
+    pub fn topic(&self) -> Option<H256> {
+        match self {
+            Self::InEvent { topic, .. } => Some(*topic),
+            _ => None,
+        }
+    }
+
+    /// name of the event parameter, that failed to be decoded
+    pub fn field(&self) -> Option<&str> {
+        match self {
+            Self::InEvent { source, .. } => source.field(),
+            Self::InField { field, .. } | Self::InvalidValue(field) => Some(field),
+            _ => None,
+        }
+    }
+
+    /// offset in the data, where decoding failed
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::InEvent { source, .. } => source.offset(),
+            Self::InField { offset, source, .. } => source.offset().or(*offset),
+            Self::NoData(offset)
+            | Self::InvalidOffset(offset)
+            | Self::InvalidLength(offset)
+            | Self::InvalidText(offset) => Some(*offset),
+            _ => None,
+        }
+    }
+
+    /// the underlying error, without the context of the event and the field
+    pub fn cause(&self) -> &Self {
+        match self {
+            Self::InEvent { source, .. } | Self::InField { source, .. } => source.cause(),
+            _ => self,
+        }
+    }
 }
 
 pub struct LogReader {
@@ -22,21 +102,22 @@ pub struct LogReader {
 }
 
 impl LogReader {
-    pub fn new(
-        log: &web3::types::Log,
+    pub fn new<L: RawLog + ?Sized>(
+        log: &L,
         expected_topics: usize,
         expected_data_size: Option<usize>,
     ) -> Result<Self, EventParseError> {
-        if log.topics.len() == 0 {
+        let topics = log.topics();
+        if topics.is_empty() {
             return Err(EventParseError::NoTopics);
         }
-        if log.topics.len() - 1 != expected_topics {
+        if topics.len() - 1 != expected_topics {
             return Err(EventParseError::InvalidTopics(
-                log.topics.len() - 1,
+                topics.len() - 1,
                 expected_topics,
             ));
         }
-        let data: &Vec<u8> = &log.data.0;
+        let data = log.data();
         if let Some(sz) = expected_data_size {
             if data.len() != sz * 32 {
                 return Err(EventParseError::InvalidDataSize(data.len(), sz));
@@ -45,8 +126,8 @@ impl LogReader {
         Ok(Self {
             current_topic: 1,
             data_offset: 0,
-            topics: log.topics.clone(),
-            data: data.clone(),
+            topics,
+            data: data.to_vec(),
         })
     }
 
@@ -54,183 +135,258 @@ impl LogReader {
         self.topics.len() > self.current_topic
     }
 
-    fn has_data(&self) -> bool {
-        self.data.len() > self.data_offset
+    // returns 32-byte word of data at the offset
+    fn word_at(&self, offset: usize) -> Result<U256, EventParseError> {
+        match self.data.get(offset..offset.saturating_add(32)) {
+            Some(word) => Ok(U256::from_big_endian(word)),
+            None => Err(EventParseError::NoData(offset)),
+        }
     }
 
-    fn next32(&mut self) -> String {
-        if self.has_topics() {
-            let hex_str = format!("{:?}", self.topics.get(self.current_topic).unwrap()); // string of 64
-                                                                                         // println!("next topic={}", hex_str);
-            self.current_topic += 1;
-            hex_str[2..].to_owned()
-        } else {
-            let hex_str = hex::encode(&self.data);
-            let offs: usize = 2 * self.data_offset;
-            let res: String = hex_str.chars().skip(offs).take(64).collect();
-            self.data_offset += 32;
-            res
+    // returns position in the data, that is stored in the word at the offset,
+    // checking that at least `size` bytes are available there
+    fn position_at(
+        &self,
+        offset: usize,
+        base: usize,
+        size: usize,
+    ) -> Result<usize, EventParseError> {
+        let value = self.word_at(offset)?;
+        let available = self.data.len().saturating_sub(base);
+        if value.bits() > 64 || value.low_u64() as usize > available.saturating_sub(size) {
+            return Err(EventParseError::InvalidOffset(offset));
         }
+        Ok(base + value.low_u64() as usize)
     }
 
-    // pop meta data as text
-    pub fn text(&mut self) -> String {
-        let _hex_size = self.next32(); // first byte is number
-        let _str_size = self.next32(); // second piece is actual size of the string
-        let mut s = String::from("");
-        while self.has_data() {
-            let nextword = self.next32();
-            let bts: Vec<u8> = hex::decode(nextword).unwrap();
-            bts.iter().filter(|ch| **ch != 0).for_each(|ch| {
-                if *ch == 0x1F {
-                    s.push('|');
-                } else if *ch == '\\' as u8
-                    || *ch == '"' as u8
-                    || *ch == '\'' as u8
-                    || *ch == '<' as u8
-                    || *ch == '>' as u8
-                {
-                    // preventing HTML injection
-                    s.push(' ');
-                } else if *ch > 0x1F && *ch < 0x80 {
-                    s.push(*ch as char);
-                }
-            });
+    // returns length of the dynamic value at the offset,
+    // checking that `item` bytes of each element are in the data
+    fn length_at(&self, offset: usize, item: usize) -> Result<usize, EventParseError> {
+        let value = self.word_at(offset)?;
+        let available = self.data.len().saturating_sub(offset + 32);
+        if value.bits() > 64 || value.low_u64() as usize > available / item {
+            return Err(EventParseError::InvalidLength(offset));
         }
-        s
+        Ok(value.low_u64() as usize)
     }
 
-    // pop address from the latest topic
-    pub fn address(&mut self) -> H160 {
-        let hex_str = self.next32();
-        // println!("address={}", hex_str);
-        H160::from_str(&hex_str[24..]).unwrap()
+    // returns content of `bytes` or `string`, that is encoded at the offset
+    fn bytes_at(&self, offset: usize) -> Result<&[u8], EventParseError> {
+        let len = self.length_at(offset, 1)?;
+        Ok(&self.data[offset + 32..offset + 32 + len])
     }
 
-    // pop array of addresses
-    pub fn addresses(&mut self) -> Vec<H160> {
-        let _ = self.next32();
-        let _ = self.next32();
-        let mut res = vec![];
-        while self.has_data() {
-            res.push(self.address());
-        }
-        res
+    // decodes value of the type, that is encoded at the offset.
+    // Offsets of the dynamic values are relative to `base`
+    fn token_at(
+        &self,
+        kind: &ParamKind,
+        offset: usize,
+        base: usize,
+    ) -> Result<Token, EventParseError> {
+        let token = match kind {
+            ParamKind::Bytes => {
+                let start = self.position_at(offset, base, 32)?;
+                Token::Bytes(self.bytes_at(start)?.to_vec())
+            }
+            ParamKind::String => {
+                let start = self.position_at(offset, base, 32)?;
+                let text = std::str::from_utf8(self.bytes_at(start)?)
+                    .map_err(|_| EventParseError::InvalidText(start))?;
+                Token::String(text.to_owned())
+            }
+            ParamKind::Array(inner) => {
+                let start = self.position_at(offset, base, 32)?;
+                // every item takes at least one word in the head of the array
+                let len = self.length_at(start, 32)?;
+                let items = start + 32;
+                Token::Array(
+                    (0..len)
+                        .map(|i| self.token_at(inner, items + 32 * i, items))
+                        .collect::<Result<_, _>>()?,
+                )
+            }
+            _ => Token::from_word(kind, self.word_at(offset)?)
+                .ok_or_else(|| EventParseError::OutOfRange(kind.to_string()))?,
+        };
+        Ok(token)
     }
 
-    // pop value from the latest topic or data
-    pub fn value(&mut self) -> U256 {
-        // let offs = self.data_offset;
-        let hex_str = self.next32();
-        // println!("offset={} data={}", offs, hex_str);
-        U256::from_str(hex_str.as_str()).unwrap()
+    // pop the next topic
+    pub fn topic(&mut self) -> Result<H256, EventParseError> {
+        let topic = self
+            .topics
+            .get(self.current_topic)
+            .cloned()
+            .ok_or(EventParseError::NoTopic(self.current_topic))?;
+        self.current_topic += 1;
+        Ok(topic)
     }
 
-    pub fn value224_32(&mut self) -> (U256, u64) {
-        // TODO: this must be properly decoded
-        let packed = self.value();
-        let mut value = packed;
-        value.0[0] = value.0[0] & 0xFFFFFFFF;
-        let timestamp = packed.0[0];
-        (value, timestamp)
+    // pop the next word from the topics or the head of the data
+    fn word(&mut self) -> Result<U256, EventParseError> {
+        if self.has_topics() {
+            return Ok(U256::from_big_endian(self.topic()?.as_bytes()));
+        }
+        let word = self.word_at(self.data_offset)?;
+        self.data_offset += 32;
+        Ok(word)
     }
 
-    pub fn skip(&mut self) {
-        let _ = self.value();
+    // pop value of the type, from the next topic or the head of the data.
+    // Dynamic values are read from the tail of the data by the offset in the head
+    pub fn token(&mut self, kind: &ParamKind) -> Result<Token, EventParseError> {
+        if !kind.is_dynamic() || self.has_topics() {
+            return Token::from_word(kind, self.word()?)
+                .ok_or_else(|| EventParseError::OutOfRange(kind.to_string()));
+        }
+        let token = self.token_at(kind, self.data_offset, 0);
+        self.data_offset += 32;
+        token
     }
 
-    // pop all remaining values
-    pub fn values(&mut self) -> Vec<U256> {
-        let mut res = vec![];
-        while self.has_data() {
-            res.push(self.value());
+    // pop text of the dynamic string
+    pub fn text(&mut self) -> Result<String, EventParseError> {
+        match self.token(&ParamKind::String)? {
+            Token::String(s) => Ok(s),
+            _ => Err(EventParseError::NoValue),
         }
-        res
     }
 
-    // pop bool value
-    pub fn bool(&mut self) -> bool {
-        let val = self.value().as_u64();
-        val > 0
+    // pop address from the latest topic or data
+    pub fn address(&mut self) -> Result<H160, EventParseError> {
+        let mut word = [0u8; 32];
+        self.word()?.to_big_endian(&mut word);
+        Ok(H160::from(H256::from(word)))
+    }
+
+    // pop value from the latest topic or data
+    pub fn value(&mut self) -> Result<U256, EventParseError> {
+        self.word()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rawlog::LogEntry;
     use hex_literal::hex;
-    use web3::types::{Address, Log};
 
     #[test]
     pub fn test_it_reads() {
-        let log = Log {
-            address: Address::from_low_u64_be(1),
-            topics: vec![
+        let log = LogEntry::new(
+            vec![
                 hex!("06fbd2297e6f6f7701a9cf99685a6af911cab275ec5c75ac7aaaf13b5cf3d61f").into(),
                 hex!("000000000000000000000000061b8335e1d2042975c4ed849943334bd07fb504").into(),
             ],
-            data: hex!("0000000000000000000000000000000000000000000000056bc75e2d631000000000000000000000000000000000000000000000000000056bb73f60696ee4160000000000000000000000000000000000000000000000000000000060da02bd").into(),
-            block_hash: Some(H256::from_low_u64_be(2)),
-            block_number: Some(1.into()),
-            transaction_hash: Some(H256::from_low_u64_be(3)),
-            transaction_index: Some(0.into()),
-            log_index: Some(0.into()),
-            transaction_log_index: Some(0.into()),
-            log_type: None,
-            removed: Some(true),
-        };
+            hex!("0000000000000000000000000000000000000000000000056bc75e2d631000000000000000000000000000000000000000000000000000056bb73f60696ee4160000000000000000000000000000000000000000000000000000000060da02bd").to_vec(),
+        );
         let mut r = LogReader::new(&log, 1, Some(3)).unwrap();
         assert_eq!(
-            r.address(),
+            r.address().unwrap(),
             hex!("061b8335e1d2042975c4ed849943334bd07fb504").into()
         );
         assert_eq!(
-            r.value(),
+            r.value().unwrap(),
             hex!("0000000000000000000000000000000000000000000000056bc75e2d63100000").into()
         );
         assert_eq!(
-            r.value(),
+            r.value().unwrap(),
             hex!("0000000000000000000000000000000000000000000000056bb73f60696ee416").into()
         );
         assert_eq!(
-            r.value(),
+            r.value().unwrap(),
             hex!("0000000000000000000000000000000000000000000000000000000060da02bd").into()
         );
     }
 
     #[test]
     pub fn test_reads_meta_data() {
-        let log = Log {
-            address: Address::from_low_u64_be(1),
-            topics: vec![
+        let log = LogEntry::new(
+            vec![
                 hex!("4d72fe0577a3a3f7da968d7b892779dde102519c25527b29cf7054f245c791b9").into(),
                 hex!("0000000000000000000000000000000000000000000000000000000000000000").into(),
                 hex!("000000000000000000000000061b8335e1d2042975c4ed849943334bd07fb504").into()
             ],
-            data: hex!(
+            hex!(
                 "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000047311f7472616e7366657228616464726573732c75696e74323536291f4d7920666972737420415049332070726f706f73616c1f466f722074657374696e6720707572706f73657300000000000000000000000000000000000000000000000000"
-            ).into(),
-            block_hash: Some(H256::from_low_u64_be(2)),
-            block_number: Some(1.into()),
-            transaction_hash: Some(H256::from_low_u64_be(3)),
-            transaction_index: Some(0.into()),
-            log_index: Some(0.into()),
-            transaction_log_index: Some(0.into()),
-            log_type: None,
-            removed: Some(true),
-        };
+            ).to_vec(),
+        );
         let mut r = LogReader::new(&log, 2, None).unwrap();
         assert_eq!(
-            r.value(),
+            r.value().unwrap(),
             hex!("0000000000000000000000000000000000000000000000000000000000000000").into()
         );
         assert_eq!(
-            r.address(),
+            r.address().unwrap(),
             hex!("061b8335e1d2042975c4ed849943334bd07fb504").into()
         );
         assert_eq!(
-            r.text(),
-            "1|transfer(address,uint256)|My first API3 proposal|For testing purposes"
+            r.text().unwrap(),
+            "1\u{1f}transfer(address,uint256)\u{1f}My first API3 proposal\u{1f}For testing purposes"
         );
     }
+
+    fn log(topics: usize, data: Vec<u8>) -> LogEntry {
+        LogEntry::new(vec![H256::from_low_u64_be(1); topics + 1], data)
+    }
+
+    fn words(list: &[u64]) -> Vec<u8> {
+        list.iter()
+            .flat_map(|x| H256::from_low_u64_be(*x).as_bytes().to_vec())
+            .collect()
+    }
+
+    // bytes, padded to the multiple of 32 bytes
+    fn padded(src: &[u8]) -> Vec<u8> {
+        let mut out = src.to_vec();
+        out.resize(src.len().div_ceil(32) * 32, 0);
+        out
+    }
+
+    #[test]
+    pub fn test_reads_dynamic_values_by_offset() {
+        // (string, uint256, string[]) where the string is not the last value
+        let text = "h\u{e9}llo <b>";
+        let mut data = words(&[0x60, 7, 0xa0, text.len() as u64]);
+        data.extend(padded(text.as_bytes()));
+        data.extend(words(&[2, 0x40, 0x80, 1]));
+        data.extend(padded(b"a"));
+        data.extend(words(&[0]));
+        let mut r = LogReader::new(&log(0, data), 0, None).unwrap();
+        assert_eq!(r.text().unwrap(), text);
+        assert_eq!(r.value().unwrap(), U256::from(7));
+        let list = ParamKind::Array(Box::new(ParamKind::String));
+        assert_eq!(
+            r.token(&list).unwrap(),
+            Token::Array(vec![
+                Token::String("a".to_owned()),
+                Token::String("".to_owned())
+            ])
+        );
+    }
+
+    #[test]
+    pub fn test_rejects_broken_dynamic_values() {
+        // invalid UTF-8
+        let mut data = words(&[0x20, 2]);
+        data.extend(padded(&[0xff, 0xfe]));
+        let mut r = LogReader::new(&log(0, data), 0, None).unwrap();
+        assert!(matches!(r.text(), Err(EventParseError::InvalidText(0x20))));
+        // offset outside of the data
+        let mut r = LogReader::new(&log(0, words(&[0x40, 0])), 0, None).unwrap();
+        assert!(matches!(r.text(), Err(EventParseError::InvalidOffset(0))));
+        // length exceeds the data
+        let mut r = LogReader::new(&log(0, words(&[0x20, 33])), 0, None).unwrap();
+        assert!(matches!(
+            r.text(),
+            Err(EventParseError::InvalidLength(0x20))
+        ));
+        let mut r = LogReader::new(&log(0, words(&[0x20, 2, 1])), 0, None).unwrap();
+        let list = ParamKind::Array(Box::new(ParamKind::Address));
+        assert!(matches!(
+            r.token(&list),
+            Err(EventParseError::InvalidLength(0x20))
+        ));
+    }
 }
@@ -0,0 +1,320 @@
+//! Logs, that events are decoded from, independent of the library that fetched them.
+//!
+//! `RawLog` is implemented for `LogEntry` (the item of `eth_getLogs` JSON-RPC result)
+//! and for the log types of `web3`, `ethers` and `alloy`, enabled with the same-named features.
+use ethereum_types::{H160, H256};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+/// Log of the transaction, as it is stored on the chain
+pub trait RawLog {
+    /// topics of the log, the first one is the hash of the event signature
+    fn topics(&self) -> Vec<H256>;
+    /// data of the log, with values of the non-indexed parameters
+    fn data(&self) -> &[u8];
+    /// number of the block, or `None` for the pending log
+    fn block_number(&self) -> Option<u64> {
+        None
+    }
+    /// hash of the transaction, or `None` for the pending log
+    fn transaction_hash(&self) -> Option<H256> {
+        None
+    }
+    /// position of the log in the block
+    fn log_index(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Log, as it is returned by `eth_getLogs` JSON-RPC method
+///
+/// ```
+/// use airnode_events::{AirnodeEvent, LogEntry};
+///
+/// let result = r#"[{
+///     "address": "0x32d228b5d44fd18fefbfd68bfe5a5f3f75c873ae",
+///     "topics": ["0x0000000000000000000000000000000000000000000000000000000000000000"],
+///     "data": "0x",
+///     "blockNumber": "0xc5d488",
+///     "transactionHash": "0x3c2ebb8a2ae8dcdb2b5a6dd3e2b5fb2cb2b6e7d1b1c5fe4bd8c4bf1ad3b6e2aa",
+///     "logIndex": "0x1"
+/// }]"#;
+/// let logs: Vec<LogEntry> = serde_json::from_str(result).unwrap();
+/// assert_eq!(logs[0].block_number, Some(12965000));
+/// assert!(matches!(AirnodeEvent::from_log(&logs[0]), Ok(AirnodeEvent::Unknown)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogEntry {
+    #[serde(default)]
+    pub address: H160,
+    pub topics: Vec<H256>,
+    #[serde(with = "hex_bytes")]
+    pub data: Vec<u8>,
+    #[serde(default, with = "hex_quantity")]
+    pub block_number: Option<u64>,
+    #[serde(default)]
+    pub transaction_hash: Option<H256>,
+    #[serde(default, with = "hex_quantity")]
+    pub log_index: Option<u64>,
+}
+
+impl LogEntry {
+    pub fn new(topics: Vec<H256>, data: Vec<u8>) -> Self {
+        Self {
+            topics,
+            data,
+            ..Default::default()
+        }
+    }
+}
+
+impl RawLog for LogEntry {
+    fn topics(&self) -> Vec<H256> {
+        self.topics.clone()
+    }
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+    fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
+    fn transaction_hash(&self) -> Option<H256> {
+        self.transaction_hash
+    }
+    fn log_index(&self) -> Option<u64> {
+        self.log_index
+    }
+}
+
+// `0x`-prefixed hex string of the bytes
+mod hex_bytes {
+    use super::*;
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(value)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        hex::decode(s.trim_start_matches("0x")).map_err(de::Error::custom)
+    }
+}
+
+// `0x`-prefixed hex string of the number, like `0x1b4`
+mod hex_quantity {
+    use super::*;
+    use serde::Serializer;
+
+    pub fn serialize<S: Serializer>(value: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_str(&format!("{:#x}", v)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => u64::from_str_radix(s.trim_start_matches("0x"), 16)
+                .map(Some)
+                .map_err(de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
+#[cfg(feature = "web3")]
+impl RawLog for web3::types::Log {
+    fn topics(&self) -> Vec<H256> {
+        self.topics.clone()
+    }
+    fn data(&self) -> &[u8] {
+        &self.data.0
+    }
+    fn block_number(&self) -> Option<u64> {
+        self.block_number.map(|n| n.as_u64())
+    }
+    fn transaction_hash(&self) -> Option<H256> {
+        self.transaction_hash
+    }
+    fn log_index(&self) -> Option<u64> {
+        self.log_index
+            .filter(|n| n.bits() <= 64)
+            .map(|n| n.low_u64())
+    }
+}
+
+#[cfg(feature = "ethers")]
+impl RawLog for ethers_core::types::Log {
+    fn topics(&self) -> Vec<H256> {
+        self.topics.iter().map(|t| H256(t.0)).collect()
+    }
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+    fn block_number(&self) -> Option<u64> {
+        self.block_number.map(|n| n.as_u64())
+    }
+    fn transaction_hash(&self) -> Option<H256> {
+        self.transaction_hash.map(|t| H256(t.0))
+    }
+    fn log_index(&self) -> Option<u64> {
+        self.log_index
+            .filter(|n| n.bits() <= 64)
+            .map(|n| n.low_u64())
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl RawLog for alloy_primitives::Log {
+    fn topics(&self) -> Vec<H256> {
+        self.data.topics().iter().map(|t| H256(t.0)).collect()
+    }
+    fn data(&self) -> &[u8] {
+        &self.data.data
+    }
+}
+
+#[cfg(feature = "alloy")]
+impl RawLog for alloy_rpc_types_eth::Log {
+    fn topics(&self) -> Vec<H256> {
+        self.inner.topics()
+    }
+    fn data(&self) -> &[u8] {
+        RawLog::data(&self.inner)
+    }
+    fn block_number(&self) -> Option<u64> {
+        self.block_number
+    }
+    fn transaction_hash(&self) -> Option<H256> {
+        self.transaction_hash.map(|t| H256(t.0))
+    }
+    fn log_index(&self) -> Option<u64> {
+        self.log_index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AirnodeEvent;
+    use ethereum_types::U256;
+    use hex_literal::hex;
+
+    // SetDapiName of Api3ServerV1, that is made up for the tests of adapters
+    const TOPICS: [[u8; 32]; 3] = [
+        hex!("f3a9aac9b6ac0f842cb5d9b3491cd5fc1b6a6778d97fd9529f587339865294f5"),
+        hex!("000000000000000000000000000000000000000000000000000000000000feed"),
+        hex!("4554482f55534400000000000000000000000000000000000000000000000000"),
+    ];
+    const DATA: [u8; 32] = hex!("000000000000000000000000000000000000000000000000000000000000005e");
+
+    fn assert_set_dapi_name<L: RawLog + ?Sized>(log: &L) {
+        match AirnodeEvent::from_log(log).unwrap() {
+            AirnodeEvent::SetDapiName {
+                data_feed_id,
+                dapi_name,
+                sender,
+            } => {
+                assert_eq!(data_feed_id, U256::from(0xfeed));
+                assert_eq!(dapi_name, "ETH/USD");
+                assert_eq!(sender, H160::from_low_u64_be(0x5e));
+            }
+            x => panic!("unexpected {:?}", x),
+        }
+    }
+
+    #[test]
+    fn it_reads_json_logs() {
+        let entry: LogEntry = serde_json::from_str(
+            r#"{
+                "address": "0xa0ad79d995ddeeb18a14eaef56a549a04e3aa1bd",
+                "topics": [
+                    "0xc0977dab79883641ece94bb6a932ca83049f561ffff8d8daaeafdbc1acce9e0a",
+                    "0x0000000000000000000000000000000000000000000000000000000000000007"
+                ],
+                "data": "0x0000000000000000000000000000000000000000000000000000000000000001",
+                "blockHash": "0x6f4c8b1d3a8c4f2ea2a3d4b1d0a9c2e7f1e0b6d5c4a3b2c1d0e9f8a7b6c5d4e3",
+                "blockNumber": "0x1b4",
+                "transactionHash": null,
+                "transactionIndex": "0x0",
+                "logIndex": "0x2",
+                "removed": false
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(entry.topics().len(), 2);
+        assert_eq!(RawLog::data(&entry)[31], 1);
+        assert_eq!(RawLog::block_number(&entry), Some(0x1b4));
+        assert_eq!(RawLog::transaction_hash(&entry), None);
+        assert_eq!(RawLog::log_index(&entry), Some(2));
+        let json = serde_json::to_string(&entry).unwrap();
+        assert_eq!(serde_json::from_str::<LogEntry>(&json).unwrap(), entry);
+        assert!(serde_json::from_str::<LogEntry>(r#"{"topics": [], "data": "0xzz"}"#).is_err());
+    }
+
+    #[cfg(feature = "web3")]
+    #[test]
+    fn it_decodes_web3_logs() {
+        let log = web3::types::Log {
+            address: H160::zero(),
+            topics: TOPICS.iter().map(|t| H256(*t)).collect(),
+            data: web3::types::Bytes(DATA.to_vec()),
+            block_hash: None,
+            block_number: Some(7.into()),
+            transaction_hash: None,
+            transaction_index: None,
+            log_index: Some(2.into()),
+            transaction_log_index: None,
+            log_type: None,
+            removed: None,
+        };
+        assert_set_dapi_name(&log);
+        assert_eq!(RawLog::block_number(&log), Some(7));
+        assert_eq!(RawLog::log_index(&log), Some(2));
+    }
+
+    #[cfg(feature = "ethers")]
+    #[test]
+    fn it_decodes_ethers_logs() {
+        let log = ethers_core::types::Log {
+            topics: TOPICS
+                .iter()
+                .map(|t| ethers_core::types::H256(*t))
+                .collect(),
+            data: DATA.to_vec().into(),
+            block_number: Some(7.into()),
+            log_index: Some(2.into()),
+            ..Default::default()
+        };
+        assert_set_dapi_name(&log);
+        assert_eq!(RawLog::block_number(&log), Some(7));
+        assert_eq!(RawLog::log_index(&log), Some(2));
+    }
+
+    #[cfg(feature = "alloy")]
+    #[test]
+    fn it_decodes_alloy_logs() {
+        let inner = alloy_primitives::Log::new(
+            alloy_primitives::Address::ZERO,
+            TOPICS
+                .iter()
+                .map(|t| alloy_primitives::B256::from(*t))
+                .collect(),
+            DATA.to_vec().into(),
+        )
+        .unwrap();
+        assert_set_dapi_name(&inner);
+        let log = alloy_rpc_types_eth::Log {
+            inner,
+            block_number: Some(7),
+            log_index: Some(2),
+            ..Default::default()
+        };
+        assert_set_dapi_name(&log);
+        assert_eq!(RawLog::block_number(&log), Some(7));
+        assert_eq!(RawLog::log_index(&log), Some(2));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::AirnodeEvent;
-use web3::types::{H160, U256};
+use airnode_abi::PartialABI;
+use ethereum_types::{H160, U256};
 
 pub fn get_template_id(evt: &AirnodeEvent) -> Option<U256> {
     match evt {
@@ -18,6 +19,51 @@ pub fn get_template_id(evt: &AirnodeEvent) -> Option<U256> {
             Some(template_id.clone())
         }
         AirnodeEvent::UpdatedBeacon { template_id, .. } => Some(template_id.clone()),
+        AirnodeEvent::MadeRequest { template_id, .. } => Some(template_id.clone()),
+        AirnodeEvent::MadeRequestRelayed { template_id, .. } => Some(template_id.clone()),
+        AirnodeEvent::StoredTemplate { template_id, .. } => Some(template_id.clone()),
+        AirnodeEvent::StoredSubscription { template_id, .. } => Some(template_id.clone()),
+        _ => None,
+    }
+}
+
+/// returns ID of the beacon or the beacon set, that is updated or named by the event
+pub fn get_data_feed_id(evt: &AirnodeEvent) -> Option<U256> {
+    match evt {
+        // beacons of the beta protocol are identified by their templates
+        AirnodeEvent::UpdatedBeacon { template_id, .. } => Some(template_id.clone()),
+        AirnodeEvent::SetDapiName { data_feed_id, .. } => Some(data_feed_id.clone()),
+        AirnodeEvent::UpdatedBeaconSetWithBeacons { beacon_set_id, .. } => {
+            Some(beacon_set_id.clone())
+        }
+        AirnodeEvent::UpdatedBeaconSetWithSignedData { beacon_set_id, .. } => {
+            Some(beacon_set_id.clone())
+        }
+        AirnodeEvent::UpdatedBeaconWithPsp { beacon_id, .. } => Some(beacon_id.clone()),
+        AirnodeEvent::UpdatedBeaconWithRrp { beacon_id, .. } => Some(beacon_id.clone()),
+        AirnodeEvent::UpdatedBeaconWithSignedData { beacon_id, .. } => Some(beacon_id.clone()),
+        AirnodeEvent::UpdatedOevProxyBeaconSetWithSignedData { beacon_set_id, .. } => {
+            Some(beacon_set_id.clone())
+        }
+        AirnodeEvent::UpdatedOevProxyBeaconWithSignedData { beacon_id, .. } => {
+            Some(beacon_id.clone())
+        }
+        _ => None,
+    }
+}
+
+/// returns ID of the subscription, that is stored, allocated or served by the event
+pub fn get_subscription_id(evt: &AirnodeEvent) -> Option<U256> {
+    match evt {
+        AirnodeEvent::StoredSubscription {
+            subscription_id, ..
+        } => Some(subscription_id.clone()),
+        AirnodeEvent::SetSlot {
+            subscription_id, ..
+        } => Some(subscription_id.clone()),
+        AirnodeEvent::UpdatedBeaconWithPsp {
+            subscription_id, ..
+        } => Some(subscription_id.clone()),
         _ => None,
     }
 }
@@ -34,6 +80,17 @@ pub fn get_endpoint_id(evt: &AirnodeEvent) -> Option<U256> {
         AirnodeEvent::SetWhitelistStatusPastExpiration { endpoint_id, .. } => {
             Some(endpoint_id.clone())
         }
+        AirnodeEvent::ExtendedAuthorizationExpiration { endpoint_id, .. } => {
+            Some(endpoint_id.clone())
+        }
+        AirnodeEvent::RevokedIndefiniteAuthorizationStatus { endpoint_id, .. } => {
+            Some(endpoint_id.clone())
+        }
+        AirnodeEvent::SetAuthorizationExpiration { endpoint_id, .. } => Some(endpoint_id.clone()),
+        AirnodeEvent::SetIndefiniteAuthorizationStatus { endpoint_id, .. } => {
+            Some(endpoint_id.clone())
+        }
+        AirnodeEvent::StoredTemplate { endpoint_id, .. } => Some(endpoint_id.clone()),
         _ => None,
     }
 }
@@ -60,6 +117,18 @@ pub fn get_fulfill_function_id(evt: &AirnodeEvent) -> Option<u64> {
             fulfill_function_id,
             ..
         } => Some(fulfill_function_id.clone()),
+        AirnodeEvent::MadeRequest {
+            fulfill_function_id,
+            ..
+        } => Some(fulfill_function_id.clone()),
+        AirnodeEvent::MadeRequestRelayed {
+            fulfill_function_id,
+            ..
+        } => Some(fulfill_function_id.clone()),
+        AirnodeEvent::StoredSubscription {
+            fulfill_function_id,
+            ..
+        } => Some(fulfill_function_id.clone()),
         _ => None,
     }
 }
@@ -138,6 +207,7 @@ pub fn get_request_id(evt: &AirnodeEvent) -> Option<U256> {
         AirnodeEvent::ErroredBeaconUpdate { request_id, .. } => Some(request_id.clone()),
         AirnodeEvent::FailedRequest { request_id, .. } => Some(request_id.clone()),
         AirnodeEvent::FulfilledRequest { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::FulfilledRequestV0 { request_id, .. } => Some(request_id.clone()),
         AirnodeEvent::FulfilledWithdrawal {
             withdrawal_request_id,
             ..
@@ -151,6 +221,13 @@ pub fn get_request_id(evt: &AirnodeEvent) -> Option<U256> {
             ..
         } => Some(withdrawal_request_id.clone()),
         AirnodeEvent::UpdatedBeacon { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::UpdatedBeaconWithRrp { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::MadeRequest { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::FulfilledRequestV1 { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::FailedRequestV1 { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::MadeRequestRelayed { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::FulfilledRequestRelayed { request_id, .. } => Some(request_id.clone()),
+        AirnodeEvent::FailedRequestRelayed { request_id, .. } => Some(request_id.clone()),
         _ => None,
     }
 }
@@ -161,6 +238,7 @@ pub fn get_airnode(evt: &AirnodeEvent) -> Option<H160> {
         AirnodeEvent::ExtendedWhitelistExpiration { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::FailedRequest { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::FulfilledRequest { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::FulfilledRequestV0 { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::FulfilledWithdrawal { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::MadeFullRequest { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::MadeTemplateRequest { airnode, .. } => Some(airnode.clone()),
@@ -168,6 +246,49 @@ pub fn get_airnode(evt: &AirnodeEvent) -> Option<H160> {
         AirnodeEvent::SetAirnodeXpub { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::SetWhitelistExpiration { airnode, .. } => Some(airnode.clone()),
         AirnodeEvent::SetWhitelistStatusPastExpiration { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::ExtendedAuthorizationExpiration { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::RevokedIndefiniteAuthorizationStatus { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::SetAuthorizationExpiration { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::SetIndefiniteAuthorizationStatus { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::MadeRequest { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::FulfilledRequestV1 { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::FailedRequestV1 { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::MadeRequestRelayed { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::FulfilledRequestRelayed { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::FailedRequestRelayed { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::StoredSubscription { airnode, .. } => Some(airnode.clone()),
+        AirnodeEvent::SetSlot { airnode, .. } => Some(airnode.clone()),
+        _ => None,
+    }
+}
+
+pub fn get_fulfillment_data(evt: &AirnodeEvent) -> Option<Vec<U256>> {
+    match evt {
+        AirnodeEvent::ClientRequestFulfilledA { data, .. } => Some(data.clone()),
+        AirnodeEvent::ClientRequestFulfilledWithBytesA { data, .. } => Some(data.clone()),
+        AirnodeEvent::RequestFulfilledA { data, .. } => Some(vec![data.clone()]),
+        AirnodeEvent::RequestFulfilledWithBytesA { data, .. } => Some(data.clone()),
+        AirnodeEvent::FulfilledRequest { data, .. } => Some(data.clone()),
+        AirnodeEvent::FulfilledRequestV0 { data, .. } => Some(data.clone()),
+        AirnodeEvent::FulfilledRequestV1 { data, .. } => Some(data.clone()),
+        AirnodeEvent::FulfilledRequestRelayed { data, .. } => Some(data.clone()),
+        _ => None,
+    }
+}
+
+pub fn get_partial_parameters(evt: &AirnodeEvent) -> Option<&PartialABI> {
+    match evt {
+        AirnodeEvent::ClientFullRequestCreatedA { partial, .. } => partial.as_ref(),
+        AirnodeEvent::ClientRequestCreatedA { partial, .. } => partial.as_ref(),
+        AirnodeEvent::ClientShortRequestCreatedA { partial, .. } => partial.as_ref(),
+        AirnodeEvent::TemplateCreatedA { partial, .. } => partial.as_ref(),
+        AirnodeEvent::CreatedTemplate { partial, .. } => partial.as_ref(),
+        AirnodeEvent::MadeFullRequest { partial, .. } => partial.as_ref(),
+        AirnodeEvent::MadeTemplateRequest { partial, .. } => partial.as_ref(),
+        AirnodeEvent::MadeRequest { partial, .. } => partial.as_ref(),
+        AirnodeEvent::MadeRequestRelayed { partial, .. } => partial.as_ref(),
+        AirnodeEvent::StoredTemplate { partial, .. } => partial.as_ref(),
+        AirnodeEvent::StoredSubscription { partial, .. } => partial.as_ref(),
         _ => None,
     }
 }
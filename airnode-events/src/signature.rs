@@ -0,0 +1,401 @@
+use crate::logreader::{EventParseError, LogReader};
+use airnode_abi::I256;
+use ethereum_types::{H160, H256, U256};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// Solidity type of the event parameter
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParamKind {
+    Address,
+    Bool,
+    Uint(usize),
+    Int(usize),
+    FixedBytes(usize),
+    Bytes,
+    String,
+    Array(Box<ParamKind>),
+}
+
+impl ParamKind {
+    /// returns whether the value is encoded in the tail of the data
+    pub fn is_dynamic(&self) -> bool {
+        matches!(self, Self::Bytes | Self::String | Self::Array(_))
+    }
+}
+
+impl fmt::Display for ParamKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => write!(f, "address"),
+            Self::Bool => write!(f, "bool"),
+            Self::Uint(bits) => write!(f, "uint{}", bits),
+            Self::Int(bits) => write!(f, "int{}", bits),
+            Self::FixedBytes(size) => write!(f, "bytes{}", size),
+            Self::Bytes => write!(f, "bytes"),
+            Self::String => write!(f, "string"),
+            Self::Array(inner) => write!(f, "{}[]", inner),
+        }
+    }
+}
+
+impl FromStr for ParamKind {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        if let Some(inner) = src.strip_suffix("[]") {
+            return Ok(Self::Array(Box::new(inner.parse()?)));
+        }
+        let sized = |prefix: &str, max: usize, step: usize| -> Option<usize> {
+            let rest = src.strip_prefix(prefix)?;
+            let size = if rest.is_empty() {
+                max
+            } else {
+                rest.parse().ok()?
+            };
+            if size > 0 && size <= max && size % step == 0 {
+                Some(size)
+            } else {
+                None
+            }
+        };
+        match src {
+            "address" => Some(Self::Address),
+            "bool" => Some(Self::Bool),
+            "bytes" => Some(Self::Bytes),
+            "string" => Some(Self::String),
+            _ if src.starts_with("uint") => sized("uint", 256, 8).map(Self::Uint),
+            _ if src.starts_with("int") => sized("int", 256, 8).map(Self::Int),
+            _ if src.starts_with("bytes") => sized("bytes", 32, 1).map(Self::FixedBytes),
+            _ => None,
+        }
+        .ok_or_else(|| format!("unsupported type {}", src))
+    }
+}
+
+/// Parameter of the event, as it is declared in the contract
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventParam {
+    pub name: String,
+    pub kind: ParamKind,
+    pub indexed: bool,
+}
+
+/// Event declaration, like `SetAirnodeXpub(address indexed airnode, string xpub)`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventSignature {
+    pub name: String,
+    pub params: Vec<EventParam>,
+}
+
+impl FromStr for EventSignature {
+    type Err = String;
+
+    /// parses declaration of the event, as it is written in Solidity
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let src = src.trim().trim_end_matches(';');
+        let (name, rest) = src
+            .split_once('(')
+            .ok_or_else(|| format!("no parameters in {}", src))?;
+        let list = rest
+            .strip_suffix(')')
+            .ok_or_else(|| format!("unclosed parameters in {}", src))?;
+        let mut params = vec![];
+        for (i, decl) in list.split(',').map(str::trim).enumerate() {
+            if decl.is_empty() {
+                continue;
+            }
+            let words: Vec<&str> = decl.split_whitespace().collect();
+            let indexed = words.get(1) == Some(&"indexed");
+            let name = match words.len() {
+                1 => format!("_{}", i),
+                2 if indexed => format!("_{}", i),
+                2 | 3 => words[words.len() - 1].to_owned(),
+                _ => return Err(format!("invalid parameter {}", decl)),
+            };
+            params.push(EventParam {
+                name,
+                kind: words[0].parse()?,
+                indexed,
+            });
+        }
+        Ok(Self {
+            name: name.trim().to_owned(),
+            params,
+        })
+    }
+}
+
+impl EventSignature {
+    /// returns canonical form of the signature, like `SetAirnodeXpub(address,string)`,
+    /// that is hashed into the first topic of the log
+    pub fn canonical(&self) -> String {
+        let types: Vec<String> = self.params.iter().map(|p| p.kind.to_string()).collect();
+        format!("{}({})", self.name, types.join(","))
+    }
+
+    /// returns number of the indexed parameters
+    pub fn indexed(&self) -> usize {
+        self.params.iter().filter(|p| p.indexed).count()
+    }
+
+    /// reads values of all parameters from the log, in the order of the declaration
+    pub fn decode(&self, r: &mut LogReader) -> Result<EventValues, EventParseError> {
+        // indexed parameters are in the topics, the others are in the data
+        let mut tokens = vec![None; self.params.len()];
+        for (i, p) in self.params.iter().enumerate().filter(|(_, p)| p.indexed) {
+            let token = if p.kind.is_dynamic() {
+                // only the hash of the dynamic value is stored in the topic
+                r.topic().map(Token::Topic)
+            } else {
+                r.token(&p.kind)
+            };
+            tokens[i] = Some(token.map_err(|e| in_field(p, None, e))?);
+        }
+        for (i, p) in self.params.iter().enumerate().filter(|(_, p)| !p.indexed) {
+            let offset = r.data_offset;
+            tokens[i] = Some(r.token(&p.kind).map_err(|e| in_field(p, Some(offset), e))?);
+        }
+        Ok(EventValues {
+            tokens: self
+                .params
+                .iter()
+                .zip(tokens)
+                .rev()
+                .filter_map(|(p, t)| Some((p.name.clone(), t?)))
+                .collect(),
+        })
+    }
+}
+
+fn in_field(p: &EventParam, offset: Option<usize>, e: EventParseError) -> EventParseError {
+    EventParseError::InField {
+        field: p.name.clone(),
+        offset,
+        source: Box::new(e),
+    }
+}
+
+/// Decoded value of the event parameter
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Address(H160),
+    Bool(bool),
+    Uint(U256),
+    Int(I256),
+    FixedBytes(Vec<u8>),
+    Bytes(Vec<u8>),
+    String(String),
+    Array(Vec<Token>),
+    /// hash of the indexed dynamic value
+    Topic(H256),
+}
+
+impl Token {
+    /// converts 32-byte word of the static type into the token,
+    /// or `None` if the integer does not fit into the declared number of bits
+    pub fn from_word(kind: &ParamKind, word: U256) -> Option<Self> {
+        let token = match kind {
+            ParamKind::Address => Self::Address(H160::from_slice(&to_bytes(word)[12..])),
+            ParamKind::Bool => Self::Bool(!word.is_zero()),
+            ParamKind::Uint(bits) if word.bits() > *bits => return None,
+            ParamKind::Uint(_) => Self::Uint(word),
+            ParamKind::Int(bits) if !is_sign_extended(word, *bits) => return None,
+            ParamKind::Int(_) => Self::Int(I256::from_raw(word)),
+            ParamKind::FixedBytes(size) => Self::FixedBytes(to_bytes(word)[..*size].to_vec()),
+            // dynamic values are never stored in a single word
+            _ => Self::FixedBytes(to_bytes(word).to_vec()),
+        };
+        Some(token)
+    }
+}
+
+// whether bits above the sign bit of `intN` are all copies of it,
+// like they are in the word of ABI-encoded `intN` value
+fn is_sign_extended(word: U256, bits: usize) -> bool {
+    if bits >= 256 {
+        return true;
+    }
+    let upper = word >> bits;
+    if word.bit(bits - 1) {
+        upper == U256::MAX >> bits
+    } else {
+        upper.is_zero()
+    }
+}
+
+fn to_bytes(word: U256) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    word.to_big_endian(&mut out);
+    out
+}
+
+// left-aligned word of the byte string, like it is stored in `bytesN` values
+fn to_word(bytes: &[u8]) -> U256 {
+    let mut word = [0u8; 32];
+    word[..bytes.len()].copy_from_slice(bytes);
+    U256::from_big_endian(&word)
+}
+
+/// Conversion of the decoded value into the field of `AirnodeEvent`
+pub trait FromToken: Sized {
+    fn from_token(token: Token) -> Option<Self>;
+}
+
+impl FromToken for U256 {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Uint(x) => Some(x),
+            Token::Int(x) => Some(x.into_raw()),
+            Token::FixedBytes(x) => Some(to_word(&x)),
+            Token::Topic(x) => Some(U256::from_big_endian(x.as_bytes())),
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for u64 {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Uint(x) if x.bits() <= 64 => Some(x.low_u64()),
+            // function selector and other short byte strings
+            Token::FixedBytes(x) if x.len() <= 8 => {
+                Some(x.iter().fold(0, |acc, b| (acc << 8) | *b as u64))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for u32 {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Uint(x) if x.bits() <= 32 => Some(x.low_u32()),
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for I256 {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Int(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for H160 {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Address(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for bool {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Bool(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+/// `string` values, and `bytes32` names (like `ETH/USD`) that are padded with zeros
+impl FromToken for String {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::String(x) => Some(x),
+            Token::FixedBytes(x) => {
+                let len = x.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+                String::from_utf8(x[..len].to_vec()).ok()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FromToken for Vec<H160> {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Array(list) => list.into_iter().map(H160::from_token).collect(),
+            _ => None,
+        }
+    }
+}
+
+/// `bytes` and `bytes32` values as the list of 32-byte words, the last word is padded with zeros
+impl FromToken for Vec<U256> {
+    fn from_token(token: Token) -> Option<Self> {
+        match token {
+            Token::Bytes(x) => Some(x.chunks(32).map(to_word).collect()),
+            Token::FixedBytes(x) => Some(vec![to_word(&x)]),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded values of the event parameters, that are taken in the order of the declaration
+#[derive(Debug, Clone)]
+pub struct EventValues {
+    // reversed, so the next value is popped from the end
+    tokens: Vec<(String, Token)>,
+}
+
+impl EventValues {
+    /// takes the next value, converted into the type of the field
+    pub fn next<T: FromToken>(&mut self) -> Result<T, EventParseError> {
+        let (name, token) = self.tokens.pop().ok_or(EventParseError::NoValue)?;
+        T::from_token(token).ok_or(EventParseError::InvalidValue(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_parses_signatures() {
+        let sig: EventSignature =
+            "FailedRequest(address indexed airnode, bytes32 indexed requestId, string errorMessage)"
+                .parse()
+                .unwrap();
+        assert_eq!(sig.name, "FailedRequest");
+        assert_eq!(sig.canonical(), "FailedRequest(address,bytes32,string)");
+        assert_eq!(sig.indexed(), 2);
+        assert_eq!(
+            sig.params[2],
+            EventParam {
+                name: "errorMessage".to_owned(),
+                kind: ParamKind::String,
+                indexed: false,
+            }
+        );
+        let sig: EventSignature = "EndpointUpdated(bytes32 indexed, bytes32, address[] list)"
+            .parse()
+            .unwrap();
+        assert_eq!(sig.params[0].name, "_0");
+        assert_eq!(sig.params[1].name, "_1");
+        assert_eq!(sig.params[2].kind.to_string(), "address[]");
+        assert!("Broken(uint7 x)".parse::<EventSignature>().is_err());
+        assert!("Broken(uint256 x".parse::<EventSignature>().is_err());
+    }
+
+    #[test]
+    fn it_checks_integer_ranges() {
+        let int224 = ParamKind::Int(224);
+        let minus_one = Token::from_word(&int224, U256::MAX);
+        assert_eq!(minus_one, Some(Token::Int(I256::MINUS_ONE)));
+        // sign bit of int224 is not extended to the upper bits
+        assert_eq!(Token::from_word(&int224, U256::one() << 223), None);
+        assert!(Token::from_word(&int224, (U256::one() << 223) - 1).is_some());
+        assert!(Token::from_word(&ParamKind::Uint(32), U256::from(u32::MAX)).is_some());
+        assert_eq!(
+            Token::from_word(&ParamKind::Uint(32), U256::one() << 32),
+            None
+        );
+    }
+}
@@ -8,7 +8,7 @@ pub enum EndpointOperation {
     Post { path: String },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "in")]
 pub enum OperationParameter {
@@ -18,25 +18,43 @@ pub enum OperationParameter {
     Cookie { name: String },
 }
 
+impl OperationParameter {
+    /// returns name of the parameter in the API operation
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Path { name } | Self::Query { name } => name,
+            Self::Header { name } | Self::Cookie { name } => name,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Parameter {
-    default: Option<String>,
-    description: Option<String>,
-    example: Option<String>,
-    name: String,
-    operation_parameter: OperationParameter,
-    required: Option<bool>,
+    pub default: Option<String>,
+    pub description: Option<String>,
+    pub example: Option<String>,
+    pub name: String,
+    pub operation_parameter: OperationParameter,
+    pub required: Option<bool>,
+}
+
+impl Parameter {
+    /// returns whether the requester must provide the parameter,
+    /// i.e. it is required and has no default value
+    pub fn is_mandatory(&self) -> bool {
+        self.required.unwrap_or(false) && self.default.is_none()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FixedParameter {
-    operation_parameter: OperationParameter,
-    value: String,
+    pub operation_parameter: OperationParameter,
+    pub value: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReservedParameterName {
     #[serde(rename = "_path")]
     Path,
@@ -48,12 +66,31 @@ pub enum ReservedParameterName {
     RelayMetadata,
 }
 
+impl ReservedParameterName {
+    pub const ALL: [Self; 4] = [Self::Path, Self::Times, Self::Type, Self::RelayMetadata];
+
+    /// returns name of the parameter, as it is used in the request
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Path => "_path",
+            Self::Times => "_times",
+            Self::Type => "_type",
+            Self::RelayMetadata => "_relay_metadata",
+        }
+    }
+
+    /// returns reserved parameter by its name in the request
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|x| x.as_str() == name).copied()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReservedParameter {
-    default: Option<String>,
-    fixed: Option<String>,
-    name: ReservedParameterName,
+    pub default: Option<String>,
+    pub fixed: Option<String>,
+    pub name: ReservedParameterName,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +14,13 @@ pub struct OIS {
     endpoints: Vec<endpoint::Endpoint>,
 }
 
+impl OIS {
+    /// returns endpoint by its name
+    pub fn endpoint(&self, name: &str) -> Option<&endpoint::Endpoint> {
+        self.endpoints.iter().find(|e| e.name == name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,5 +107,12 @@ mod tests {
           }"#;
         let out: OIS = serde_json::from_str(input).unwrap();
         println!("{:#?}", out);
+        let endpoint = out.endpoint("convertToUsd").unwrap();
+        assert!(!endpoint.parameters[0].is_mandatory());
+        assert_eq!(
+            endpoint.reserved_parameters[0].fixed,
+            Some("int256".to_owned())
+        );
+        assert!(out.endpoint("convertToEur").is_none());
     }
 }
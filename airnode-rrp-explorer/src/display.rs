@@ -0,0 +1,39 @@
+//! Presentation of the decoded values, that are taken from the chain as they are.
+//! Markup is escaped by yew in text nodes and attributes, so here we only
+//! make control characters visible.
+use serde::Serialize;
+use serde_json::Value;
+
+/// returns text with the unit separator (that joins fields of the metadata)
+/// shown as `|` and other control characters shown as `\u{..}` escapes
+pub fn printable(src: &str) -> String {
+    src.chars()
+        .map(|c| match c {
+            '\u{1f}' => "|".to_owned(),
+            '\n' | '\t' => c.to_string(),
+            c if c.is_control() => c.escape_unicode().to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn printable_value(value: Value) -> Value {
+    match value {
+        Value::String(s) => Value::String(printable(&s)),
+        Value::Array(list) => Value::Array(list.into_iter().map(printable_value).collect()),
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| (printable(&k), printable_value(v)))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// returns pretty JSON of the value, with every string made printable
+pub fn to_printable_json<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(v) => serde_json::to_string_pretty(&printable_value(v)).unwrap_or_default(),
+        Err(e) => format!("{}", e),
+    }
+}
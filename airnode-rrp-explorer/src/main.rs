@@ -1,4 +1,5 @@
 pub mod components;
+pub mod display;
 pub mod entry;
 pub mod filter;
 pub mod footer;
@@ -1,3 +1,4 @@
+use crate::display::{printable, to_printable_json};
 use crate::logevent::LogEvent;
 use crate::reader::BlockBatch;
 use web3::types::H256;
@@ -94,7 +95,7 @@ impl Component for ResultsView {
                     {
                         for ctx.props().input.iter().map(|l| {
                             let (evt_class, err_text) = match &l.error {
-                                Some(txt) => ("warning", txt.clone()),
+                                Some(txt) => ("warning", printable(txt)),
                                 None => ("", "".to_owned()),
                             };
                             match &l.event {
@@ -106,7 +107,7 @@ impl Component for ResultsView {
                                     html!{
                                         <li class={cls}>
                                             {self.tx_link(chain_id, l.block_number, l.transaction_hash)}
-                                            {serde_json::to_string_pretty(&evt).unwrap() }
+                                            { to_printable_json(&evt) }
                                         </li>
                                     }
                                 },
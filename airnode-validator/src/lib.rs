@@ -0,0 +1,280 @@
+//! Validation of Airnode request parameters against the endpoint of Oracle Integration Specifications.
+//!
+//! Airnode silently ignores parameters that are not declared by the endpoint
+//! and fails the request on missing or malformed ones, so the validator reports
+//! all such problems of the decoded request at once.
+use airnode_abi::{Decimal, Param, ResponseType, ABI};
+use airnode_ois::endpoint::{Endpoint, OperationParameter, ReservedParameterName};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Error, Serialize, Deserialize)]
+pub enum ValidationError {
+    #[error("missing required parameter {0}")]
+    MissingRequired(String),
+    #[error("unknown parameter {0}")]
+    UnknownParameter(String),
+    #[error("parameter {0} is fixed by the endpoint and cannot be overridden")]
+    FixedOverride(String),
+    #[error("reserved parameter {0} is not declared by the endpoint")]
+    UndeclaredReserved(String),
+    #[error("reserved parameter {name} should be {expected}, got {actual}")]
+    InvalidReservedType {
+        name: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("invalid value {value} of reserved parameter {name}: {reason}")]
+    InvalidReservedValue {
+        name: String,
+        value: String,
+        reason: String,
+    },
+}
+
+/// All problems that were found in the request parameters
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list: Vec<String> = self.0.iter().map(|e| e.to_string()).collect();
+        write!(f, "{}", list.join("; "))
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+/// checks decoded parameters of the request against the endpoint definition.
+/// Reports every problem that was found, in the order of the parameters
+pub fn validate_request(endpoint: &Endpoint, abi: &ABI) -> Result<(), ValidationErrors> {
+    validate_params(endpoint, abi.params())
+}
+
+/// checks the list of request parameters against the endpoint definition
+pub fn validate_params(endpoint: &Endpoint, params: &[Param]) -> Result<(), ValidationErrors> {
+    let mut errors = vec![];
+    // API operation parameters, that have fixed values
+    let fixed: HashSet<&OperationParameter> = endpoint
+        .fixed_operation_parameters
+        .iter()
+        .map(|p| &p.operation_parameter)
+        .collect();
+
+    for param in params {
+        let name = param.get_name();
+        if let Some(reserved) = ReservedParameterName::from_name(name) {
+            let definition = endpoint
+                .reserved_parameters
+                .iter()
+                .find(|r| r.name == reserved);
+            match definition {
+                None => errors.push(ValidationError::UndeclaredReserved(name.to_owned())),
+                Some(r) if r.fixed.is_some() => {
+                    errors.push(ValidationError::FixedOverride(name.to_owned()))
+                }
+                Some(_) => {
+                    if let Err(e) = check_reserved(reserved, param) {
+                        errors.push(e);
+                    }
+                }
+            }
+            continue;
+        }
+        // the declared parameter overrides the fixed one, if it is mapped to the same operation parameter
+        match endpoint.parameters.iter().find(|p| p.name == name) {
+            Some(p) if fixed.contains(&p.operation_parameter) => {
+                errors.push(ValidationError::FixedOverride(name.to_owned()))
+            }
+            Some(_) => {}
+            None => errors.push(ValidationError::UnknownParameter(name.to_owned())),
+        }
+    }
+
+    let provided: HashSet<&str> = params.iter().map(|p| p.get_name()).collect();
+    for p in &endpoint.parameters {
+        if p.is_mandatory() && !provided.contains(p.name.as_str()) {
+            errors.push(ValidationError::MissingRequired(p.name.clone()));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationErrors(errors))
+    }
+}
+
+/// checks that the reserved parameter is a string with the value Airnode can process
+fn check_reserved(reserved: ReservedParameterName, param: &Param) -> Result<(), ValidationError> {
+    let name = reserved.as_str();
+    let value = match param {
+        Param::String { value, .. } | Param::String32 { value, .. } => value,
+        _ => {
+            return Err(ValidationError::InvalidReservedType {
+                name: name.to_owned(),
+                expected: "string32 or string".to_owned(),
+                actual: param.get_type().to_owned(),
+            })
+        }
+    };
+    let invalid = |reason: &str| ValidationError::InvalidReservedValue {
+        name: name.to_owned(),
+        value: value.clone(),
+        reason: reason.to_owned(),
+    };
+    match reserved {
+        ReservedParameterName::Times => {
+            // empty items are allowed for the types that are not multiplied
+            for item in value.split(',').map(str::trim).filter(|x| !x.is_empty()) {
+                item.parse::<Decimal>()
+                    .map_err(|_| invalid("not a number"))?;
+            }
+        }
+        ReservedParameterName::Type => {
+            for item in value.split(',') {
+                item.trim()
+                    .parse::<ResponseType>()
+                    .map_err(|e| invalid(&e.to_string()))?;
+            }
+        }
+        ReservedParameterName::Path | ReservedParameterName::RelayMetadata => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use airnode_abi::AbiBuilder;
+    use airnode_ois::endpoint::Parameter;
+
+    fn endpoint() -> Endpoint {
+        serde_json::from_str(
+            r#"{
+            "name": "convertToUsd",
+            "operation": { "path": "/convert", "method": "get" },
+            "fixedOperationParameters": [
+                { "operationParameter": { "name": "to", "in": "query" }, "value": "USD" }
+            ],
+            "reservedParameters": [
+                { "name": "_type", "fixed": "int256" },
+                { "name": "_path", "default": "data.0.price" },
+                { "name": "_times" }
+            ],
+            "parameters": [
+                {
+                    "name": "from",
+                    "required": true,
+                    "operationParameter": { "name": "from", "in": "query" }
+                },
+                {
+                    "name": "amount",
+                    "required": true,
+                    "default": "1",
+                    "operationParameter": { "name": "amount", "in": "query" }
+                }
+            ]
+        }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_accepts_valid_request() {
+        let abi = AbiBuilder::new()
+            .string32("from", "ETH")
+            .string("_path", "data.price")
+            .string32("_times", "1000000")
+            .build()
+            .unwrap();
+        assert_eq!(validate_request(&endpoint(), &abi), Ok(()));
+    }
+
+    #[test]
+    fn it_reports_all_problems() {
+        let abi = AbiBuilder::new()
+            .string32("_type", "uint256")
+            .string32("to", "EUR")
+            .string32("_relay_metadata", "v1")
+            .string32("chain", "1")
+            .uint("_times", 100u64)
+            .build()
+            .unwrap();
+        let errors = validate_request(&endpoint(), &abi).unwrap_err().0;
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::FixedOverride("_type".to_owned()),
+                // names of the fixed operation parameters are not request parameters
+                ValidationError::UnknownParameter("to".to_owned()),
+                ValidationError::UndeclaredReserved("_relay_metadata".to_owned()),
+                ValidationError::UnknownParameter("chain".to_owned()),
+                ValidationError::InvalidReservedType {
+                    name: "_times".to_owned(),
+                    expected: "string32 or string".to_owned(),
+                    actual: "uint256".to_owned(),
+                },
+                ValidationError::MissingRequired("from".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_reports_overrides_of_fixed_operation_parameters() {
+        let mut endpoint = endpoint();
+        endpoint.parameters.extend(
+            serde_json::from_str::<Vec<Parameter>>(
+                r#"[
+                { "name": "currency", "operationParameter": { "name": "to", "in": "query" } },
+                { "name": "target", "operationParameter": { "name": "to", "in": "header" } }
+            ]"#,
+            )
+            .unwrap(),
+        );
+        let abi = AbiBuilder::new()
+            .string32("from", "ETH")
+            .string32("currency", "EUR")
+            .string32("target", "EUR")
+            .build()
+            .unwrap();
+        let errors = validate_request(&endpoint, &abi).unwrap_err().0;
+        assert_eq!(
+            errors,
+            vec![ValidationError::FixedOverride("currency".to_owned())]
+        );
+    }
+
+    #[test]
+    fn it_checks_reserved_values() {
+        let abi = AbiBuilder::new()
+            .string32("from", "ETH")
+            .string32("_times", "1e6,abc")
+            .build()
+            .unwrap();
+        let errors = validate_request(&endpoint(), &abi).unwrap_err();
+        assert_eq!(
+            errors.to_string(),
+            "invalid value 1e6,abc of reserved parameter _times: not a number"
+        );
+
+        let mut endpoint = endpoint();
+        endpoint.reserved_parameters[0].fixed = None;
+        let abi = AbiBuilder::new()
+            .string32("from", "ETH")
+            .string32("_type", "int256,float")
+            .build()
+            .unwrap();
+        let errors = validate_request(&endpoint, &abi).unwrap_err().0;
+        assert_eq!(
+            errors,
+            vec![ValidationError::InvalidReservedValue {
+                name: "_type".to_owned(),
+                value: "int256,float".to_owned(),
+                reason: "invalid type float".to_owned(),
+            }]
+        );
+    }
+}